@@ -2,22 +2,38 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter};
 
 use crate::blocking::run_blocking;
-pub use crate::storage::BackendMode;
+use crate::lima::{self, limactl_command};
+pub use crate::storage::{BackendMode, VmResources};
 use crate::{containers, storage};
 
 static VM_OP_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 static VM_ENSURE_INFLIGHT: OnceLock<Mutex<HashMap<String, Arc<EnsureInFlight>>>> =
     OnceLock::new();
+static CANCEL_TOKENS: OnceLock<Mutex<HashMap<String, (Arc<AtomicBool>, usize)>>> =
+    OnceLock::new();
 const VM_SHELL_TIMEOUT_SECS: u32 = 20;
 const VM_START_TIMEOUT_SECS: u32 = 120;
 const VM_CREATE_TIMEOUT_SECS: u32 = 180;
 const VM_BOOTSTRAP_TIMEOUT_SECS: u32 = 240;
+/// Bump when `BOOTSTRAP_PACKAGES` or the bootstrap script changes so existing
+/// VMs re-run it on their next start.
+const BOOTSTRAP_VERSION: u32 = 2;
+const BOOTSTRAP_PACKAGES: &[&str] = &["unzip", "zip", "git", "curl", "ca-certificates", "rsync"];
+const BACKEND_COMMAND_TIMEOUT_SECS: u32 = 300;
+const KILL_REAP_GRACE: Duration = Duration::from_secs(2);
+pub(crate) const CANCELED_MESSAGE: &str = "Operation canceled.";
+
+thread_local! {
+    static CURRENT_CANCEL: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
 
 #[derive(Debug, Serialize, Clone)]
 struct VmStatusEvent {
@@ -75,10 +91,109 @@ impl EnsureInFlight {
     }
 }
 
+/// Marks the current thread as working on behalf of `repo_path` so that
+/// `request_cancel` can interrupt it. Nested scopes reuse the outer token, and
+/// a scope opened after a cancel gets a fresh one.
+pub struct CancelScope {
+    key: Option<String>,
+}
+
+pub fn cancel_scope(repo_path: &Path) -> CancelScope {
+    if cancel_scope_active() {
+        return CancelScope { key: None };
+    }
+    let key = repo_path.to_string_lossy().to_string();
+    let token = {
+        let mut map = CANCEL_TOKENS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        let entry = map
+            .entry(key.clone())
+            .or_insert_with(|| (Arc::new(AtomicBool::new(false)), 0));
+        if entry.0.load(Ordering::SeqCst) {
+            // Scopes already holding the tripped token stay canceled.
+            entry.0 = Arc::new(AtomicBool::new(false));
+        }
+        entry.1 += 1;
+        entry.0.clone()
+    };
+    CURRENT_CANCEL.with(|current| *current.borrow_mut() = Some(token));
+    CancelScope { key: Some(key) }
+}
+
+impl Drop for CancelScope {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        CURRENT_CANCEL.with(|current| *current.borrow_mut() = None);
+        let mut map = CANCEL_TOKENS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some(entry) = map.get_mut(&key) {
+            entry.1 = entry.1.saturating_sub(1);
+            if entry.1 == 0 {
+                map.remove(&key);
+            }
+        }
+    }
+}
+
+fn cancel_scope_active() -> bool {
+    CURRENT_CANCEL.with(|current| current.borrow().is_some())
+}
+
+pub(crate) fn cancel_requested() -> bool {
+    CURRENT_CANCEL.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .map(|token| token.load(Ordering::SeqCst))
+            .unwrap_or(false)
+    })
+}
+
+pub(crate) fn check_canceled() -> Result<(), String> {
+    if cancel_requested() {
+        Err(CANCELED_MESSAGE.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Flags every in-flight operation for the repo as canceled. Returns false
+/// when nothing was running.
+pub fn request_cancel(repo_path: &Path) -> bool {
+    let key = repo_path.to_string_lossy().to_string();
+    let map = CANCEL_TOKENS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    match map.get(&key) {
+        Some((token, _)) => {
+            token.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
 fn provider_id(provider: VmProvider) -> &'static str {
     match provider {
         VmProvider::Lima => "lima",
         VmProvider::Wsl => "wsl",
+        VmProvider::Container => "container",
+    }
+}
+
+fn provider_from_id(id: &str) -> Option<VmProvider> {
+    match id {
+        "lima" => Some(VmProvider::Lima),
+        "wsl" => Some(VmProvider::Wsl),
+        "container" => Some(VmProvider::Container),
+        _ => None,
     }
 }
 
@@ -112,6 +227,8 @@ fn emit_vm_status(
 pub enum VmProvider {
     Lima,
     Wsl,
+    /// A long-lived Docker/Podman container on the host, used by the container backend.
+    Container,
 }
 
 #[derive(Debug, Clone)]
@@ -132,8 +249,17 @@ pub struct VmProcessHandle {
 
 #[derive(Debug, Clone)]
 pub enum BackendProcess {
-    Host { pid: u32 },
-    Virtualized { pid: u32, vm: VmProcessHandle },
+    Host {
+        pid: u32,
+    },
+    Virtualized {
+        pid: u32,
+        vm: VmProcessHandle,
+    },
+    Container {
+        handle: containers::ContainerHandle,
+        limactl: PathBuf,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -151,10 +277,18 @@ impl BackendContext {
     }
 }
 
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct BackendModeEvent {
+    repo_path: Option<String>,
+    mode: BackendMode,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct BackendPrereqStatus {
     pub installed: bool,
     pub tool: String,
+    pub version: Option<String>,
     pub message: Option<String>,
 }
 
@@ -165,6 +299,31 @@ pub struct BackendEnsureResult {
     pub provider: Option<String>,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct CommandOutput {
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Resource usage inside a repo's VM. Sizes are in MiB.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct BackendStats {
+    pub cpus: u32,
+    pub memory_total_mb: u64,
+    pub memory_used_mb: u64,
+    pub disk_total_mb: u64,
+    pub disk_used_mb: u64,
+}
+
+/// What the repo's VM can do, probed without changing it.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct BackendCapabilities {
+    pub nerdctl: bool,
+    pub sudo_nopasswd: bool,
+    pub bootstrap_done: bool,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct BackendVmInfo {
     pub name: String,
@@ -187,6 +346,7 @@ fn tool_name(provider: VmProvider) -> &'static str {
     match provider {
         VmProvider::Lima => "Lima",
         VmProvider::Wsl => "WSL",
+        VmProvider::Container => "Docker or Podman",
     }
 }
 
@@ -249,22 +409,42 @@ fn check_wsl_available() -> bool {
     status.map(|result| result.success()).unwrap_or(false)
 }
 
-fn limactl_path(app: Option<&AppHandle>) -> Option<PathBuf> {
-    app.and_then(|handle| containers::limactl_path(handle))
+fn first_output_line(output: &[u8]) -> Option<String> {
+    // `wsl.exe` writes UTF-16LE; dropping the NUL bytes is enough for the
+    // ASCII version banner.
+    let text = String::from_utf8_lossy(output).replace('\0', "");
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
 }
 
-fn limactl_command(limactl: Option<&Path>) -> Command {
-    if let Some(path) = limactl {
-        Command::new(path)
-    } else {
-        Command::new("limactl")
+fn tool_version(provider: VmProvider, limactl: Option<&Path>) -> Option<String> {
+    let mut cmd = match provider {
+        VmProvider::Lima => {
+            return containers::limactl_version(limactl.unwrap_or(Path::new("limactl")));
+        }
+        VmProvider::Wsl => Command::new("wsl"),
+        VmProvider::Container => Command::new(container_engine()?),
+    };
+    cmd.arg("--version");
+    apply_shell_env(&mut cmd);
+    let output = cmd.output().ok()?;
+    if !output.status.success() {
+        return None;
     }
+    first_output_line(&output.stdout)
+}
+
+fn limactl_path(app: Option<&AppHandle>) -> Option<PathBuf> {
+    app.and_then(|handle| containers::limactl_path(handle))
 }
 
 fn ensure_prereq(provider: VmProvider, limactl: Option<&Path>) -> Result<(), String> {
     let installed = match provider {
         VmProvider::Lima => limactl.is_some() || command_exists("limactl"),
         VmProvider::Wsl => check_wsl_available(),
+        VmProvider::Container => container_engine().is_some(),
     };
     if installed {
         Ok(())
@@ -276,8 +456,47 @@ fn ensure_prereq(provider: VmProvider, limactl: Option<&Path>) -> Result<(), Str
     }
 }
 
-fn effective_backend_mode(app: &AppHandle) -> Result<BackendMode, String> {
-    if let Some(mode) = storage::get_backend_mode_raw(app)? {
+fn provider_for_mode(mode: BackendMode) -> Result<VmProvider, String> {
+    match mode {
+        BackendMode::Container => {
+            ensure_container_mode_supported()?;
+            Ok(VmProvider::Container)
+        }
+        _ => vm_provider(),
+    }
+}
+
+/// The host container uses `--network host`, which only exposes app ports on
+/// Linux. Docker Desktop on macOS/Windows keeps them inside its own VM.
+fn container_mode_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+fn ensure_container_mode_supported() -> Result<(), String> {
+    if container_mode_supported() {
+        Ok(())
+    } else {
+        Err("The container backend is only supported on Linux.".to_string())
+    }
+}
+
+/// Backend modes that can be selected on this OS.
+fn supported_backend_modes() -> Vec<BackendMode> {
+    let mut modes = vec![BackendMode::Host];
+    if vm_provider().is_ok() {
+        modes.push(BackendMode::Virtualized);
+    }
+    if container_mode_supported() {
+        modes.push(BackendMode::Container);
+    }
+    modes
+}
+
+fn effective_backend_mode(
+    app: &AppHandle,
+    repo_path: Option<&Path>,
+) -> Result<BackendMode, String> {
+    if let Some(mode) = storage::get_backend_mode_raw(app, repo_path)? {
         return Ok(mode);
     }
     let provider = match vm_provider() {
@@ -288,6 +507,7 @@ fn effective_backend_mode(app: &AppHandle) -> Result<BackendMode, String> {
     let installed = match provider {
         VmProvider::Lima => limactl.is_some() || command_exists("limactl"),
         VmProvider::Wsl => check_wsl_available(),
+        VmProvider::Container => container_engine().is_some(),
     };
     Ok(if installed {
         BackendMode::Virtualized
@@ -311,6 +531,7 @@ fn ensure_vm_bootstrap(
     let sudo_hint = match provider {
         VmProvider::Lima => format!("limactl shell {} -- sudo -v", name),
         VmProvider::Wsl => format!("wsl -d {} -- sudo -v", name),
+        VmProvider::Container => format!("{} exec -u root {} true", container_engine_name(), name),
     };
     let script = format!(
         r#"
@@ -333,7 +554,23 @@ if [ "$needs_env" -eq 0 ]; then
   done
 fi
 
-if [ -f /var/lib/falck/bootstrap_v1 ] && [ "$needs_env" -eq 0 ]; then
+installed_version=0
+for marker in /var/lib/falck/bootstrap_v*; do
+  [ -f "$marker" ] || continue
+  version="${{marker##*_v}}"
+  case "$version" in
+    ''|*[!0-9]*) continue ;;
+  esac
+  if [ "$version" -gt "$installed_version" ]; then
+    installed_version="$version"
+  fi
+done
+needs_packages=0
+if [ "$installed_version" -lt {bootstrap_version} ]; then
+  needs_packages=1
+fi
+
+if [ "$needs_packages" -eq 0 ] && [ "$needs_env" -eq 0 ]; then
   exit 0
 fi
 
@@ -378,27 +615,30 @@ EOF
   upsert_env "POLL" "1"
 }}
 
-if [ ! -f /var/lib/falck/bootstrap_v1 ]; then
+if [ "$needs_packages" -ne 0 ]; then
   $SUDO mkdir -p /var/lib/falck
   if command -v apt-get >/dev/null 2>&1; then
     $SUDO DEBIAN_FRONTEND=noninteractive apt-get -y -o Dpkg::Lock::Timeout=60 update
-    $SUDO DEBIAN_FRONTEND=noninteractive apt-get -y -o Dpkg::Lock::Timeout=60 install unzip zip git curl ca-certificates rsync
+    $SUDO DEBIAN_FRONTEND=noninteractive apt-get -y -o Dpkg::Lock::Timeout=60 install {packages}
   elif command -v dnf >/dev/null 2>&1; then
-    $SUDO dnf -y install unzip zip git curl ca-certificates rsync
+    $SUDO dnf -y install {packages}
   elif command -v apk >/dev/null 2>&1; then
-    $SUDO apk add --no-cache unzip zip git curl ca-certificates rsync
+    $SUDO apk add --no-cache {packages}
   else
     echo "No supported package manager found on VM." >&2
     exit 1
   fi
-  $SUDO touch /var/lib/falck/bootstrap_v1
+  $SUDO rm -f /var/lib/falck/bootstrap_v*
+  $SUDO touch /var/lib/falck/bootstrap_v{bootstrap_version}
 fi
 
 if [ "$needs_env" -ne 0 ]; then
   ensure_polling_env
 fi
 "#,
-        sudo_hint = sudo_hint
+        sudo_hint = sudo_hint,
+        bootstrap_version = BOOTSTRAP_VERSION,
+        packages = BOOTSTRAP_PACKAGES.join(" ")
     );
     let cmd = build_vm_command(&vm, &script);
     let (status, stdout, stderr) =
@@ -505,6 +745,48 @@ fn lima_mounts_yq(repo_path: &Path) -> String {
     )
 }
 
+fn lima_resources_yq(resources: &VmResources) -> Vec<String> {
+    let mut exprs = Vec::new();
+    if let Some(cpus) = resources.vm_cpus {
+        exprs.push(format!(".cpus = {}", cpus));
+    }
+    if let Some(memory) = resources.vm_memory_gib {
+        exprs.push(format!(".memory = \"{}GiB\"", memory));
+    }
+    if let Some(disk) = resources.vm_disk_gib {
+        exprs.push(format!(".disk = \"{}GiB\"", disk));
+    }
+    exprs
+}
+
+fn vm_resources(app: Option<&AppHandle>) -> VmResources {
+    app.and_then(|app| storage::get_vm_resources(app).ok())
+        .unwrap_or_default()
+}
+
+fn lima_size_matches(current: Option<&str>, desired_gib: u32) -> bool {
+    current
+        .map(|value| value.trim().replace(' ', ""))
+        .is_some_and(|value| value.eq_ignore_ascii_case(&format!("{}GiB", desired_gib)))
+}
+
+// Lima cannot resize an existing instance, so any drift needs a VM reset.
+fn lima_vm_needs_reset(name: &str, resources: &VmResources) -> bool {
+    let Some(config) = read_lima_config(name) else {
+        return false;
+    };
+    let cpus_differ = resources
+        .vm_cpus
+        .is_some_and(|cpus| config.cpus != Some(cpus));
+    let memory_differ = resources
+        .vm_memory_gib
+        .is_some_and(|memory| !lima_size_matches(config.memory.as_deref(), memory));
+    let disk_differ = resources
+        .vm_disk_gib
+        .is_some_and(|disk| !lima_size_matches(config.disk.as_deref(), disk));
+    cpus_differ || memory_differ || disk_differ
+}
+
 fn normalize_ports(ports: &[u16]) -> Vec<u16> {
     let mut set = BTreeSet::new();
     for port in ports {
@@ -531,6 +813,22 @@ fn lima_port_forwards_yq(ports: &[u16]) -> Option<String> {
     ))
 }
 
+fn lima_remove_port_forwards_yq(ports: &[u16]) -> Option<String> {
+    let ports = normalize_ports(ports);
+    if ports.is_empty() {
+        return None;
+    }
+    let keep = ports
+        .iter()
+        .map(|port| format!(".guestPort != {port}"))
+        .collect::<Vec<_>>()
+        .join(" and ");
+    Some(format!(
+        ".portForwards = ((.portForwards // []) | map(select({})))",
+        keep
+    ))
+}
+
 fn lima_legacy_mount_target(repo_path: &Path) -> String {
     let base = repo_path
         .file_name()
@@ -547,14 +845,11 @@ fn lima_instance_dir(name: &str) -> Option<PathBuf> {
         .map(|home| PathBuf::from(home).join(".lima").join(name))
 }
 
-#[derive(Debug, Deserialize)]
-struct LimaListEntry {
-    name: String,
-    status: Option<String>,
-}
-
 #[derive(Debug, Deserialize)]
 struct LimaConfig {
+    cpus: Option<u32>,
+    memory: Option<String>,
+    disk: Option<String>,
     mounts: Option<Vec<LimaMount>>,
     #[serde(rename = "portForwards")]
     port_forwards: Option<Vec<LimaPortForward>>,
@@ -573,88 +868,11 @@ struct LimaPortForward {
     guest_port: Option<u16>,
 }
 
-fn entries_from_array(items: Vec<serde_json::Value>) -> Result<Vec<LimaListEntry>, String> {
-    let mut entries = Vec::new();
-    for entry in items {
-        let parsed: LimaListEntry = serde_json::from_value(entry)
-            .map_err(|e| format!("Failed to parse Lima VM list entry: {e}"))?;
-        entries.push(parsed);
-    }
-    Ok(entries)
-}
-
-fn entries_from_value(value: serde_json::Value) -> Result<Option<Vec<LimaListEntry>>, String> {
-    match value {
-        serde_json::Value::Array(items) => Ok(Some(entries_from_array(items)?)),
-        serde_json::Value::Object(obj) => {
-            if let Some(entries) = obj.get("entries") {
-                if let serde_json::Value::Array(items) = entries {
-                    return Ok(Some(entries_from_array(items.clone())?));
-                }
-            }
-            if obj.contains_key("name") {
-                let parsed: LimaListEntry = serde_json::from_value(serde_json::Value::Object(obj))
-                    .map_err(|e| format!("Failed to parse Lima VM list entry: {e}"))?;
-                return Ok(Some(vec![parsed]));
-            }
-            Ok(None)
-        }
-        _ => Ok(None),
-    }
-}
-
-fn parse_lima_list_entries(raw: &[u8]) -> Result<Vec<LimaListEntry>, String> {
-    if raw.iter().all(|byte| byte.is_ascii_whitespace()) {
-        return Ok(Vec::new());
-    }
-    let mut slices = vec![raw];
-    if let Some(start) = raw.iter().position(|b| *b == b'{' || *b == b'[') {
-        slices.push(&raw[start..]);
-    }
-
-    let mut last_error = None;
-    let mut entries = Vec::new();
-    let mut parsed_any = false;
-    for slice in slices {
-        let mut stream =
-            serde_json::Deserializer::from_slice(slice).into_iter::<serde_json::Value>();
-        while let Some(result) = stream.next() {
-            match result {
-                Ok(value) => {
-                    parsed_any = true;
-                    match entries_from_value(value) {
-                        Ok(Some(mut next)) => {
-                            entries.append(&mut next);
-                        }
-                        Ok(None) => {}
-                        Err(err) => {
-                            last_error = Some(err);
-                        }
-                    }
-                }
-                Err(err) => {
-                    last_error = Some(format!("Failed to parse Lima VM list: {err}"));
-                    break;
-                }
-            }
-        }
-        if !entries.is_empty() {
-            return Ok(entries);
-        }
-    }
-    if entries.is_empty() && parsed_any && last_error.is_none() {
-        return Ok(Vec::new());
-    }
-    Err(last_error.unwrap_or_else(|| {
-        "Failed to parse Lima VM list: unexpected JSON format.".to_string()
-    }))
-}
-
 fn lima_instance_registered(name: &str, limactl: Option<&Path>) -> bool {
     lima_instance_info(name, limactl).is_some()
 }
 
-fn lima_instance_info(name: &str, limactl: Option<&Path>) -> Option<LimaListEntry> {
+fn lima_instance_info(name: &str, limactl: Option<&Path>) -> Option<lima::LimaListEntry> {
     let output = {
         let mut cmd = limactl_command(limactl);
         cmd.args(["list", "--json", "--tty=false"]);
@@ -667,7 +885,7 @@ fn lima_instance_info(name: &str, limactl: Option<&Path>) -> Option<LimaListEntr
     if !output.status.success() {
         return None;
     }
-    parse_lima_list_entries(&output.stdout)
+    lima::parse_list_entries(&output.stdout)
         .ok()
         .and_then(|items| items.into_iter().find(|item| item.name == name))
 }
@@ -824,7 +1042,7 @@ fn list_lima_vms(app: &AppHandle) -> Result<Vec<BackendVmInfo>, String> {
     if !output.status.success() {
         return Err("Failed to list Lima VMs.".to_string());
     }
-    let entries = parse_lima_list_entries(&output.stdout)?;
+    let entries = lima::parse_list_entries(&output.stdout)?;
     let repo_map = build_repo_vm_map(app)?;
     let mut vms = Vec::new();
     for entry in entries {
@@ -887,6 +1105,25 @@ fn wsl_instance_running(name: &str) -> bool {
         .any(|(vm_name, state)| vm_name == name && normalize_vm_status(Some(state)) == "running")
 }
 
+fn wsl_instance_registered(name: &str) -> bool {
+    let output = {
+        let mut cmd = Command::new("wsl");
+        cmd.args(["-l", "-v"]);
+        apply_shell_env(&mut cmd);
+        cmd.output()
+    };
+    let Ok(output) = output else {
+        return false;
+    };
+    if !output.status.success() {
+        return false;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_wsl_list(&stdout)
+        .iter()
+        .any(|(vm_name, _)| vm_name == name)
+}
+
 fn list_wsl_vms(app: &AppHandle) -> Result<Vec<BackendVmInfo>, String> {
     if !command_exists("wsl") {
         return Err("WSL is not installed.".to_string());
@@ -932,6 +1169,15 @@ fn lima_forwarded_ports(name: &str) -> HashSet<u16> {
     ports
 }
 
+/// Guest ports currently forwarded by the VM's Lima config.
+pub fn vm_forwarded_ports(vm: &VmContext) -> Vec<u16> {
+    if vm.provider != VmProvider::Lima {
+        return Vec::new();
+    }
+    let ports: Vec<u16> = lima_forwarded_ports(&vm.name).into_iter().collect();
+    normalize_ports(&ports)
+}
+
 pub fn ensure_vm_port_forwards(
     app: Option<&AppHandle>,
     vm: &VmContext,
@@ -980,6 +1226,7 @@ pub fn ensure_vm_port_forwards(
         Some(&vm.repo_path),
         Some(&missing),
         vm.limactl_path.as_deref(),
+        &vm_resources(app),
     )
     .map_err(|err| {
         emit_vm_status(
@@ -1024,6 +1271,96 @@ pub fn ensure_vm_port_forwards(
     Ok(())
 }
 
+pub fn remove_vm_port_forwards(
+    app: Option<&AppHandle>,
+    vm: &VmContext,
+    ports: &[u16],
+) -> Result<(), String> {
+    if vm.provider != VmProvider::Lima {
+        return Ok(());
+    }
+    let existing = lima_forwarded_ports(&vm.name);
+    let stale: Vec<u16> = normalize_ports(ports)
+        .into_iter()
+        .filter(|port| existing.contains(port))
+        .collect();
+    let Some(expr) = lima_remove_port_forwards_yq(&stale) else {
+        return Ok(());
+    };
+
+    emit_vm_status(
+        app,
+        &vm.repo_path,
+        Some(&vm.name),
+        Some(vm.provider),
+        "stopping",
+        "Restarting VM to remove port forwards",
+    );
+
+    let _guard = vm_lock()?;
+    let _ = stop_vm_inner(vm.provider, &vm.name, vm.limactl_path.as_deref());
+    limactl_edit(&vm.name, &[expr], vm.limactl_path.as_deref()).map_err(|err| {
+        emit_vm_status(
+            app,
+            &vm.repo_path,
+            Some(&vm.name),
+            Some(vm.provider),
+            "error",
+            &format!("Failed to remove port forwards: {err}"),
+        );
+        err
+    })?;
+
+    emit_vm_status(
+        app,
+        &vm.repo_path,
+        Some(&vm.name),
+        Some(vm.provider),
+        "starting",
+        "Starting virtual machine",
+    );
+    limactl_start(
+        &vm.name,
+        Some(&vm.repo_path),
+        None,
+        vm.limactl_path.as_deref(),
+        &vm_resources(app),
+    )
+    .map_err(|err| {
+        emit_vm_status(
+            app,
+            &vm.repo_path,
+            Some(&vm.name),
+            Some(vm.provider),
+            "error",
+            &format!("Failed to start VM: {err}"),
+        );
+        err
+    })?;
+    wait_for_vm_ready(vm.provider, &vm.name, 90, vm.limactl_path.as_deref()).map_err(
+        |err| {
+            emit_vm_status(
+                app,
+                &vm.repo_path,
+                Some(&vm.name),
+                Some(vm.provider),
+                "error",
+                &format!("VM did not become ready: {err}"),
+            );
+            err
+        },
+    )?;
+    emit_vm_status(
+        app,
+        &vm.repo_path,
+        Some(&vm.name),
+        Some(vm.provider),
+        "ready",
+        "VM is ready",
+    );
+    Ok(())
+}
+
 fn windows_path_to_wsl(path: &Path) -> String {
     let raw = path.to_string_lossy();
     let trimmed = raw.trim();
@@ -1077,6 +1414,7 @@ fn get_vm_home(provider: VmProvider, name: &str, limactl: Option<&Path>) -> Resu
             apply_shell_env(&mut cmd);
             cmd
         }
+        VmProvider::Container => container_exec_command(name, "printf %s \"$HOME\""),
     };
     let (status, stdout, _stderr) =
         spawn_capture_with_timeout(cmd, Some(VM_SHELL_TIMEOUT_SECS))
@@ -1177,7 +1515,21 @@ fn resolve_repo_root(
     limactl: Option<&Path>,
 ) -> Result<String, String> {
     match provider {
-        VmProvider::Wsl => Ok(windows_path_to_wsl(repo_path)),
+        VmProvider::Wsl => {
+            let wsl_path = windows_path_to_wsl(repo_path);
+            if !path_exists_in_vm_with_retry(provider, name, &wsl_path, limactl, 5, 700) {
+                return Err(format!(
+                    "The repo path is not visible inside the VM ({wsl_path}). Network drives are not mounted in WSL; move the repo to a local drive, or use Settings > Reset VM (or `wsl --unregister {name}`) and try again."
+                ));
+            }
+            if !path_writable_in_vm_with_retry(provider, name, &wsl_path, limactl, 5, 700) {
+                return Err(format!(
+                    "The VM mount for this repo is read-only. Use Settings > Reset VM (or `wsl --unregister {name}`) and try again."
+                ));
+            }
+            Ok(wsl_path)
+        }
+        VmProvider::Container => Ok(host_container_repo_root(repo_path)),
         VmProvider::Lima => {
             let preferred = lima_mount_target(repo_path);
             if path_exists_in_vm_with_retry(provider, name, &preferred, limactl, 5, 700) {
@@ -1238,20 +1590,18 @@ fn limactl_start(
     repo_path: Option<&Path>,
     port_forwards: Option<&[u16]>,
     limactl: Option<&Path>,
+    resources: &VmResources,
 ) -> Result<(), String> {
     let cmd = {
-        let mut cmd = limactl_command(limactl);
-        cmd.args(["start", "--tty=false"]);
+        let mut sets = Vec::new();
         if let Some(repo_path) = repo_path {
-            let mounts_expr = lima_mounts_yq(repo_path);
-            cmd.args(["--set", &mounts_expr]);
+            sets.push(lima_mounts_yq(repo_path));
         }
-        if let Some(port_forwards) = port_forwards {
-            if let Some(expr) = lima_port_forwards_yq(port_forwards) {
-                cmd.args(["--set", &expr]);
-            }
+        sets.extend(lima_resources_yq(resources));
+        if let Some(expr) = port_forwards.and_then(lima_port_forwards_yq) {
+            sets.push(expr);
         }
-        cmd.arg(name);
+        let mut cmd = lima::start_command(limactl, name, &sets);
         apply_shell_env(&mut cmd);
         cmd
     };
@@ -1276,34 +1626,50 @@ fn limactl_start(
     }
 }
 
-fn limactl_create(
-    name: &str,
-    repo_path: &Path,
-    port_forwards: Option<&[u16]>,
-    limactl: Option<&Path>,
-    template_path: Option<&Path>,
-) -> Result<(), String> {
-    let mounts_expr = lima_mounts_yq(repo_path);
+fn limactl_edit(name: &str, exprs: &[String], limactl: Option<&Path>) -> Result<(), String> {
     let cmd = {
         let mut cmd = limactl_command(limactl);
-        cmd.args([
-            "create",
-            "--tty=false",
-            "--name",
-            name,
-            "--set",
-            &mounts_expr,
-        ]);
-        if let Some(template_path) = template_path {
-            cmd.arg(template_path);
-        } else {
-            cmd.arg("template:default");
-        }
-        if let Some(port_forwards) = port_forwards {
-            if let Some(expr) = lima_port_forwards_yq(port_forwards) {
-                cmd.args(["--set", &expr]);
-            }
+        cmd.args(["edit", "--tty=false"]);
+        for expr in exprs {
+            cmd.args(["--set", expr]);
         }
+        cmd.arg(name);
+        apply_shell_env(&mut cmd);
+        cmd
+    };
+    let (status, stdout, stderr) =
+        spawn_capture_with_timeout(cmd, Some(VM_START_TIMEOUT_SECS))
+            .map_err(|e| format!("Failed to edit Lima VM: {e}"))?;
+
+    if status.success() {
+        return Ok(());
+    }
+
+    let combined = format!("{}\n{}", stdout.trim(), stderr.trim())
+        .trim()
+        .to_string();
+    if combined.is_empty() {
+        Err("Lima failed to update the virtual machine config.".to_string())
+    } else {
+        Err(combined)
+    }
+}
+
+fn limactl_create(
+    name: &str,
+    repo_path: &Path,
+    port_forwards: Option<&[u16]>,
+    limactl: Option<&Path>,
+    template_path: Option<&Path>,
+    resources: &VmResources,
+) -> Result<(), String> {
+    let cmd = {
+        let mut sets = vec![lima_mounts_yq(repo_path)];
+        if let Some(expr) = port_forwards.and_then(lima_port_forwards_yq) {
+            sets.push(expr);
+        }
+        sets.extend(lima_resources_yq(resources));
+        let mut cmd = lima::create_command(limactl, name, template_path, &sets);
         apply_shell_env(&mut cmd);
         cmd
     };
@@ -1347,10 +1713,15 @@ fn wait_for_vm_ready(
                 apply_shell_env(&mut cmd);
                 spawn_with_timeout(cmd, Some(VM_SHELL_TIMEOUT_SECS), true).ok()
             }
+            VmProvider::Container => {
+                let cmd = container_exec_command(name, "true");
+                spawn_with_timeout(cmd, Some(VM_SHELL_TIMEOUT_SECS), true).ok()
+            }
         };
         if status.map(|result| result.success()).unwrap_or(false) {
             return Ok(());
         }
+        check_canceled()?;
         if start.elapsed() > timeout {
             return Err("Timed out waiting for VM to become ready.".to_string());
         }
@@ -1384,7 +1755,10 @@ fn ensure_vm_running(
         return entry.wait();
     }
 
-    let result = ensure_vm_running_inner(provider, repo_path, app, limactl);
+    let result = {
+        let _cancel = cancel_scope(repo_path);
+        ensure_vm_running_inner(provider, repo_path, app, limactl)
+    };
     entry.finish(result.clone());
     if let Ok(mut map) = VM_ENSURE_INFLIGHT
         .get_or_init(|| Mutex::new(HashMap::new()))
@@ -1456,6 +1830,10 @@ fn ensure_vm_running_inner(
         "starting",
         "VM lock acquired",
     );
+    if let Err(err) = check_canceled() {
+        emit_vm_status(app, repo_path, Some(&name), Some(provider), "error", &err);
+        return Err(err);
+    }
 
     let template_path = if provider == VmProvider::Lima {
         if let Some(app_handle) = app {
@@ -1482,14 +1860,20 @@ fn ensure_vm_running_inner(
 
     match provider {
         VmProvider::Lima => {
+            let resources = vm_resources(app);
             if lima_instance_running(&name, limactl) {
+                let message = if lima_vm_needs_reset(&name, &resources) {
+                    "VM already running (resource settings changed, reset the VM to apply them)"
+                } else {
+                    "VM already running"
+                };
                 emit_vm_status(
                     app,
                     repo_path,
                     Some(&name),
                     Some(provider),
                     "ready",
-                    "VM already running",
+                    message,
                 );
                 return Ok(name);
             }
@@ -1501,7 +1885,7 @@ fn ensure_vm_running_inner(
                 "starting",
                 &format!("Starting Lima VM {}", name),
             );
-            if limactl_start(&name, Some(repo_path), None, limactl).is_ok() {
+            if limactl_start(&name, Some(repo_path), None, limactl, &resources).is_ok() {
                 emit_vm_status(
                     app,
                     repo_path,
@@ -1553,7 +1937,7 @@ fn ensure_vm_running_inner(
                     "Existing VM found, restarting",
                 );
                 let _ = stop_vm_inner(provider, &name, limactl);
-                if let Err(err) = limactl_start(&name, Some(repo_path), None, limactl) {
+                if let Err(err) = limactl_start(&name, Some(repo_path), None, limactl, &resources) {
                     emit_vm_status(
                         app,
                         repo_path,
@@ -1640,8 +2024,15 @@ fn ensure_vm_running_inner(
                 "creating",
                 "Creating new VM",
             );
-            limactl_create(&name, repo_path, None, limactl, template_path.as_deref())
-                .map_err(|err| {
+            limactl_create(
+                &name,
+                repo_path,
+                None,
+                limactl,
+                template_path.as_deref(),
+                &resources,
+            )
+            .map_err(|err| {
                 emit_vm_status(
                     app,
                     repo_path,
@@ -1651,7 +2042,7 @@ fn ensure_vm_running_inner(
                     &format!("Failed to create VM: {err}"),
                 );
                 err
-                })?;
+            })?;
             emit_vm_status(
                 app,
                 repo_path,
@@ -1660,7 +2051,7 @@ fn ensure_vm_running_inner(
                 "starting",
                 "Starting newly created VM",
             );
-            limactl_start(&name, Some(repo_path), None, limactl).map_err(|err| {
+            limactl_start(&name, Some(repo_path), None, limactl, &resources).map_err(|err| {
                 emit_vm_status(
                     app,
                     repo_path,
@@ -1855,7 +2246,234 @@ fn ensure_vm_running_inner(
                 Err("WSL failed to create the virtual machine.".to_string())
             }
         }
+        VmProvider::Container => {
+            ensure_host_container(app, repo_path, &name)?;
+            Ok(name)
+        }
+    }
+}
+
+const HOST_CONTAINER_IMAGE: &str = "ubuntu:24.04";
+const HOST_CONTAINER_WORKSPACE: &str = "/workspace";
+
+fn container_engine() -> Option<&'static str> {
+    ["docker", "podman"]
+        .into_iter()
+        .find(|engine| command_exists(engine))
+}
+
+fn container_engine_name() -> &'static str {
+    container_engine().unwrap_or("docker")
+}
+
+fn container_engine_command() -> Command {
+    let mut cmd = Command::new(container_engine_name());
+    apply_shell_env(&mut cmd);
+    cmd
+}
+
+fn container_exec_command(name: &str, script: &str) -> Command {
+    let mut cmd = container_engine_command();
+    cmd.args(["exec", "-i", name, "sh", "-c"]);
+    cmd.arg(script);
+    cmd
+}
+
+fn host_container_repo_root(repo_path: &Path) -> String {
+    let base = repo_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("repo");
+    join_unix(HOST_CONTAINER_WORKSPACE, &sanitize_name(base))
+}
+
+/// `Some(running)` when the container exists, `None` otherwise.
+fn host_container_running(name: &str) -> Option<bool> {
+    let mut cmd = container_engine_command();
+    cmd.args(["inspect", "-f", "{{.State.Running}}", name]);
+    let output = cmd.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim() == "true")
+}
+
+/// Host containers created by `ensure_host_container`, found by their
+/// `falck.repo` label so containers of removed repos are listed too.
+fn list_host_containers() -> Result<Vec<BackendVmInfo>, String> {
+    let output = {
+        let mut cmd = container_engine_command();
+        cmd.args([
+            "ps",
+            "-a",
+            "--filter",
+            "label=falck.repo",
+            "--format",
+            "{{.Names}}",
+        ]);
+        cmd.output()
+    }
+    .map_err(|e| format!("Failed to list backend containers: {e}"))?;
+    if !output.status.success() {
+        return Err("Failed to list backend containers.".to_string());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut containers = Vec::new();
+    for name in stdout
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        let mut cmd = container_engine_command();
+        cmd.args([
+            "inspect",
+            "-f",
+            "{{.State.Running}}\t{{index .Config.Labels \"falck.repo\"}}",
+            name,
+        ]);
+        let Ok(output) = cmd.output() else {
+            continue;
+        };
+        if !output.status.success() {
+            continue;
+        }
+        let details = String::from_utf8_lossy(&output.stdout);
+        let (running, repo_path) = details
+            .trim()
+            .split_once('\t')
+            .unwrap_or((details.trim(), ""));
+        containers.push(BackendVmInfo {
+            name: name.to_string(),
+            provider: provider_id(VmProvider::Container).to_string(),
+            status: if running == "true" {
+                "running".to_string()
+            } else {
+                "stopped".to_string()
+            },
+            repo_path: (!repo_path.is_empty()).then(|| repo_path.to_string()),
+        });
     }
+    Ok(containers)
+}
+
+fn ensure_host_container(
+    app: Option<&AppHandle>,
+    repo_path: &Path,
+    name: &str,
+) -> Result<(), String> {
+    let provider = VmProvider::Container;
+    match host_container_running(name) {
+        Some(true) => {
+            emit_vm_status(
+                app,
+                repo_path,
+                Some(name),
+                Some(provider),
+                "ready",
+                "Container already running",
+            );
+            return Ok(());
+        }
+        Some(false) => {
+            emit_vm_status(
+                app,
+                repo_path,
+                Some(name),
+                Some(provider),
+                "starting",
+                &format!("Starting container {}", name),
+            );
+            let mut cmd = container_engine_command();
+            cmd.args(["start", name]);
+            let (status, stdout, stderr) =
+                spawn_capture_with_timeout(cmd, Some(VM_START_TIMEOUT_SECS))
+                    .map_err(|e| format!("Failed to start container: {e}"))?;
+            if !status.success() {
+                let combined = format!("{}\n{}", stdout.trim(), stderr.trim())
+                    .trim()
+                    .to_string();
+                return Err(if combined.is_empty() {
+                    "Failed to start the backend container.".to_string()
+                } else {
+                    combined
+                });
+            }
+        }
+        None => {
+            emit_vm_status(
+                app,
+                repo_path,
+                Some(name),
+                Some(provider),
+                "creating",
+                "Creating backend container",
+            );
+            let mount = containers::ContainerMountSpec {
+                source: containers::ContainerMountSource::Bind(repo_path.to_path_buf()),
+                target: host_container_repo_root(repo_path),
+                mode: None,
+            };
+            // Host networking keeps app ports reachable without per-port
+            // publishing; this is why the container backend is Linux-only.
+            let mut cmd = container_engine_command();
+            cmd.args(["run", "-d", "--init", "--network", "host", "--name", name]);
+            cmd.args(["--label", &format!("falck.repo={}", repo_path.display())]);
+            cmd.args(["-v", &containers::container_mount_arg(&mount)]);
+            cmd.args(["-w", &mount.target]);
+            cmd.args([HOST_CONTAINER_IMAGE, "sleep", "infinity"]);
+            let (status, stdout, stderr) =
+                spawn_capture_with_timeout(cmd, Some(VM_CREATE_TIMEOUT_SECS))
+                    .map_err(|e| format!("Failed to create container: {e}"))?;
+            if !status.success() {
+                let combined = format!("{}\n{}", stdout.trim(), stderr.trim())
+                    .trim()
+                    .to_string();
+                let message = if combined.is_empty() {
+                    "Failed to create the backend container.".to_string()
+                } else {
+                    combined
+                };
+                emit_vm_status(
+                    app,
+                    repo_path,
+                    Some(name),
+                    Some(provider),
+                    "error",
+                    &message,
+                );
+                return Err(message);
+            }
+        }
+    }
+
+    emit_vm_status(
+        app,
+        repo_path,
+        Some(name),
+        Some(provider),
+        "bootstrapping",
+        "Installing base container packages",
+    );
+    ensure_vm_bootstrap(provider, name, None).map_err(|err| {
+        emit_vm_status(
+            app,
+            repo_path,
+            Some(name),
+            Some(provider),
+            "error",
+            &format!("Container bootstrap failed: {err}"),
+        );
+        err
+    })?;
+    emit_vm_status(
+        app,
+        repo_path,
+        Some(name),
+        Some(provider),
+        "ready",
+        "Container ready",
+    );
+    Ok(())
 }
 
 fn stop_vm_inner(
@@ -1876,6 +2494,11 @@ fn stop_vm_inner(
             apply_shell_env(&mut cmd);
             cmd.status()
         }
+        VmProvider::Container => {
+            let mut cmd = container_engine_command();
+            cmd.args(["stop", name]);
+            cmd.status()
+        }
     };
     match status {
         Ok(result) if result.success() => Ok(()),
@@ -1948,6 +2571,15 @@ fn delete_vm(
                 Err(err) => Err(format!("Failed to delete virtual machine: {err}")),
             }
         }
+        VmProvider::Container => {
+            let mut cmd = container_engine_command();
+            cmd.args(["rm", "-f", name]);
+            match cmd.status() {
+                Ok(result) if result.success() => Ok(()),
+                Ok(_) => Err("Failed to delete container.".to_string()),
+                Err(err) => Err(format!("Failed to delete container: {err}")),
+            }
+        }
     }
 }
 
@@ -2005,9 +2637,24 @@ pub fn build_vm_command(vm: &VmContext, script: &str) -> Command {
             apply_shell_env(&mut cmd);
             cmd
         }
+        VmProvider::Container => container_exec_command(&vm.name, script),
     }
 }
 
+/// Kills a child and waits briefly for it to exit so it does not linger as a
+/// zombie. Gives up after a short grace period rather than blocking forever.
+pub fn kill_and_reap(child: &mut Child) {
+    let _ = child.kill();
+    let start = Instant::now();
+    while start.elapsed() < KILL_REAP_GRACE {
+        match child.try_wait() {
+            Ok(Some(_)) | Err(_) => return,
+            Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+    eprintln!("[falck][backend] process {} did not exit after kill", child.id());
+}
+
 pub fn spawn_with_timeout(
     mut cmd: Command,
     timeout_secs: Option<u32>,
@@ -2021,9 +2668,8 @@ pub fn spawn_with_timeout(
 
     let mut child = cmd.spawn().map_err(|e| format!("Failed to spawn command: {e}"))?;
 
-    if let Some(timeout) = timeout_secs {
+    if timeout_secs.is_some() || cancel_scope_active() {
         let start = Instant::now();
-        let timeout_duration = Duration::from_secs(timeout as u64);
         loop {
             if let Some(status) = child
                 .try_wait()
@@ -2031,9 +2677,15 @@ pub fn spawn_with_timeout(
             {
                 return Ok(status);
             }
-            if start.elapsed() > timeout_duration {
-                let _ = child.kill();
-                return Err(format!("Command timed out after {} seconds", timeout));
+            if cancel_requested() {
+                kill_and_reap(&mut child);
+                return Err(CANCELED_MESSAGE.to_string());
+            }
+            if let Some(timeout) = timeout_secs {
+                if start.elapsed() > Duration::from_secs(timeout as u64) {
+                    kill_and_reap(&mut child);
+                    return Err(format!("Command timed out after {} seconds", timeout));
+                }
             }
             std::thread::sleep(Duration::from_millis(200));
         }
@@ -2072,9 +2724,8 @@ pub fn spawn_capture_with_timeout(
         buf
     });
 
-    let status = if let Some(timeout) = timeout_secs {
+    let status = if timeout_secs.is_some() || cancel_scope_active() {
         let start = Instant::now();
-        let timeout_duration = Duration::from_secs(timeout as u64);
         loop {
             if let Some(status) = child
                 .try_wait()
@@ -2082,12 +2733,19 @@ pub fn spawn_capture_with_timeout(
             {
                 break status;
             }
-            if start.elapsed() > timeout_duration {
+            let error = if cancel_requested() {
+                Some(CANCELED_MESSAGE.to_string())
+            } else {
+                timeout_secs
+                    .filter(|timeout| start.elapsed() > Duration::from_secs(*timeout as u64))
+                    .map(|timeout| format!("Command timed out after {} seconds", timeout))
+            };
+            if let Some(error) = error {
                 let _ = child.kill();
                 let _ = child.wait();
                 let _ = stdout_handle.join();
                 let _ = stderr_handle.join();
-                return Err(format!("Command timed out after {} seconds", timeout));
+                return Err(error);
             }
             std::thread::sleep(Duration::from_millis(200));
         }
@@ -2107,12 +2765,12 @@ pub fn spawn_capture_with_timeout(
 }
 
 pub fn resolve_backend(app: &AppHandle, repo_path: &Path) -> Result<BackendContext, String> {
-    let mode = effective_backend_mode(app)?;
+    let mode = effective_backend_mode(app, Some(repo_path))?;
     if mode == BackendMode::Host {
         return Ok(BackendContext::host());
     }
 
-    let provider = vm_provider()?;
+    let provider = provider_for_mode(mode)?;
     let limactl = limactl_path(Some(app));
     let vm_name = ensure_vm_running(provider, repo_path, Some(app), limactl.as_deref())?;
     let repo_root = match resolve_repo_root(provider, &vm_name, repo_path, limactl.as_deref()) {
@@ -2142,11 +2800,246 @@ pub fn resolve_backend(app: &AppHandle, repo_path: &Path) -> Result<BackendConte
     })
 }
 
+/// Returns true when the VM (or host container) backing this repo has already
+/// been created. Does not start or create anything.
+pub fn repo_vm_exists(app: &AppHandle, repo_path: &Path) -> Result<bool, String> {
+    let mode = effective_backend_mode(app, Some(repo_path))?;
+    if mode == BackendMode::Host {
+        return Ok(false);
+    }
+    let provider = provider_for_mode(mode)?;
+    let name = vm_name_for_repo(repo_path);
+    Ok(match provider {
+        VmProvider::Lima => {
+            let limactl = limactl_path(Some(app));
+            lima_instance_registered(&name, limactl.as_deref())
+        }
+        VmProvider::Wsl => wsl_instance_registered(&name),
+        VmProvider::Container => host_container_running(&name).is_some(),
+    })
+}
+
+/// Runs an arbitrary shell command from the repo root in the repo's backend,
+/// falling back to the host shell when the repo uses the host backend.
+pub fn run_in_backend(
+    app: &AppHandle,
+    repo_path: &Path,
+    command: &str,
+) -> Result<CommandOutput, String> {
+    let backend = resolve_backend(app, repo_path)?;
+    let cmd = match &backend.vm {
+        Some(vm) => {
+            let script = format!("cd {} && {}", shell_escape(&vm.repo_root), command);
+            build_vm_command(vm, &script)
+        }
+        None => {
+            let mut cmd = crate::falck::build_shell_command(command);
+            cmd.current_dir(repo_path);
+            cmd
+        }
+    };
+    let (status, stdout, stderr) =
+        spawn_capture_with_timeout(cmd, Some(BACKEND_COMMAND_TIMEOUT_SECS))?;
+    Ok(CommandOutput {
+        exit_code: status.code(),
+        stdout,
+        stderr,
+    })
+}
+
+fn parse_backend_stats(output: &str) -> Result<BackendStats, String> {
+    let mut stats = BackendStats::default();
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let number = |index: usize| -> Result<u64, String> {
+            parts
+                .get(index)
+                .and_then(|value| value.parse::<u64>().ok())
+                .ok_or_else(|| format!("Unexpected VM stats line: {line}"))
+        };
+        match parts.first().copied() {
+            Some("cpus") => stats.cpus = number(1)? as u32,
+            Some("mem") => {
+                stats.memory_total_mb = number(1)?;
+                stats.memory_used_mb = number(2)?;
+            }
+            Some("disk") => {
+                stats.disk_total_mb = number(1)?;
+                stats.disk_used_mb = number(2)?;
+            }
+            _ => {}
+        }
+    }
+    if stats.cpus == 0 || stats.memory_total_mb == 0 {
+        return Err("Failed to read VM stats.".to_string());
+    }
+    Ok(stats)
+}
+
+/// Context for the repo's VM if it is already running. Never starts the VM.
+fn running_repo_vm(app: &AppHandle, repo_path: &Path) -> Result<VmContext, String> {
+    let mode = effective_backend_mode(app, Some(repo_path))?;
+    if mode == BackendMode::Host {
+        return Err("Host mode has no VM.".to_string());
+    }
+    let provider = provider_for_mode(mode)?;
+    let limactl = limactl_path(Some(app));
+    let name = vm_name_for_repo(repo_path);
+    let running = match provider {
+        VmProvider::Lima => lima_instance_running(&name, limactl.as_deref()),
+        VmProvider::Wsl => wsl_instance_running(&name),
+        VmProvider::Container => host_container_running(&name) == Some(true),
+    };
+    if !running {
+        return Err("The VM for this repo is not running.".to_string());
+    }
+    Ok(VmContext {
+        provider,
+        name,
+        repo_path: repo_path.to_path_buf(),
+        repo_root: "/".to_string(),
+        limactl_path: limactl,
+    })
+}
+
+/// Returns the tail of the Lima host-agent and serial logs for the repo's VM.
+/// Works whether or not the VM is running, which is when the logs matter most.
+pub fn read_vm_logs(app: &AppHandle, repo_path: &Path) -> Result<String, String> {
+    let mode = effective_backend_mode(app, Some(repo_path))?;
+    if mode == BackendMode::Host {
+        return Err("Host mode has no VM.".to_string());
+    }
+    if provider_for_mode(mode)? != VmProvider::Lima {
+        return Err("VM logs are only available for Lima VMs.".to_string());
+    }
+    let name = vm_name_for_repo(repo_path);
+    lima_debug_logs(&name).ok_or_else(|| format!("No Lima logs found for VM {}.", name))
+}
+
+/// Reads CPU, memory and disk usage from the repo's VM without starting it.
+pub fn read_backend_stats(app: &AppHandle, repo_path: &Path) -> Result<BackendStats, String> {
+    let vm = running_repo_vm(app, repo_path)?;
+    let script = "echo cpus $(nproc); \
+free -m | awk '/^Mem:/ {print \"mem\", $2, $3}'; \
+df -Pm / | awk 'NR==2 {print \"disk\", $2, $3}'";
+    let (status, stdout, stderr) =
+        spawn_capture_with_timeout(build_vm_command(&vm, script), Some(VM_SHELL_TIMEOUT_SECS))?;
+    if !status.success() {
+        return Err(format!("Failed to read VM stats: {}", stderr.trim()));
+    }
+    parse_backend_stats(&stdout)
+}
+
+/// Probes whether the repo's VM has nerdctl, passwordless sudo and a finished
+/// bootstrap, so container launches can be disabled up front.
+pub fn read_backend_capabilities(
+    app: &AppHandle,
+    repo_path: &Path,
+) -> Result<BackendCapabilities, String> {
+    let vm = running_repo_vm(app, repo_path)?;
+    let script = format!(
+        "if [ \"$(id -u)\" -eq 0 ] || sudo -n true >/dev/null 2>&1; then echo sudo 1; fi; \
+if command -v nerdctl >/dev/null 2>&1 || sudo -n sh -c 'command -v nerdctl' >/dev/null 2>&1; then echo nerdctl 1; fi; \
+if [ -f /var/lib/falck/bootstrap_v{} ]; then echo bootstrap 1; fi",
+        BOOTSTRAP_VERSION
+    );
+    let (status, stdout, stderr) =
+        spawn_capture_with_timeout(build_vm_command(&vm, &script), Some(VM_SHELL_TIMEOUT_SECS))?;
+    if !status.success() {
+        return Err(format!(
+            "Failed to probe VM capabilities: {}",
+            stderr.trim()
+        ));
+    }
+    let mut capabilities = BackendCapabilities::default();
+    for line in stdout.lines() {
+        match line.trim() {
+            "sudo 1" => capabilities.sudo_nopasswd = true,
+            "nerdctl 1" => capabilities.nerdctl = true,
+            "bootstrap 1" => capabilities.bootstrap_done = true,
+            _ => {}
+        }
+    }
+    Ok(capabilities)
+}
+
+pub fn delete_backend_for_repo(app: &AppHandle, repo_path: &Path) -> Result<(), String> {
+    let mode = effective_backend_mode(app, Some(repo_path))?;
+    if mode == BackendMode::Host {
+        return Ok(());
+    }
+    let provider = provider_for_mode(mode)?;
+    let limactl = limactl_path(Some(app));
+    let name = vm_name_for_repo(repo_path);
+    emit_vm_status(
+        Some(app),
+        repo_path,
+        Some(&name),
+        Some(provider),
+        "deleting",
+        "Deleting virtual machine",
+    );
+    match delete_vm(provider, &name, limactl.as_deref()) {
+        Ok(()) => {
+            emit_vm_status(
+                Some(app),
+                repo_path,
+                Some(&name),
+                Some(provider),
+                "deleted",
+                "VM deleted",
+            );
+            Ok(())
+        }
+        Err(err) => {
+            emit_vm_status(
+                Some(app),
+                repo_path,
+                Some(&name),
+                Some(provider),
+                "error",
+                &format!("Failed to delete VM: {err}"),
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Deletes the repo's VM and recreates it, re-establishing the repo mount and
+/// any port forwards the old VM had. This is the "Reset VM" action the mount
+/// error messages point to.
+pub fn reset_backend_for_repo(
+    app: &AppHandle,
+    repo_path: &Path,
+) -> Result<BackendEnsureResult, String> {
+    let mode = effective_backend_mode(app, Some(repo_path))?;
+    if mode == BackendMode::Host {
+        return Err("Host mode has no VM to reset.".to_string());
+    }
+    let provider = provider_for_mode(mode)?;
+    let forwarded: Vec<u16> = if provider == VmProvider::Lima {
+        lima_forwarded_ports(&vm_name_for_repo(repo_path))
+            .into_iter()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    delete_backend_for_repo(app, repo_path)?;
+    let result = ensure_backend_for_repo(app, repo_path)?;
+    if !forwarded.is_empty() {
+        if let Some(vm) = resolve_backend(app, repo_path)?.vm {
+            ensure_vm_port_forwards(Some(app), &vm, &forwarded)?;
+        }
+    }
+    Ok(result)
+}
+
 pub fn ensure_backend_for_repo(
     app: &AppHandle,
     repo_path: &Path,
 ) -> Result<BackendEnsureResult, String> {
-    let mode = effective_backend_mode(app)?;
+    let mode = effective_backend_mode(app, Some(repo_path))?;
     if mode == BackendMode::Host {
         return Ok(BackendEnsureResult {
             mode,
@@ -2155,7 +3048,7 @@ pub fn ensure_backend_for_repo(
         });
     }
 
-    let provider = vm_provider()?;
+    let provider = provider_for_mode(mode)?;
     let limactl = limactl_path(Some(app));
     let vm_name = ensure_vm_running(provider, repo_path, Some(app), limactl.as_deref())?;
     emit_vm_status(
@@ -2191,16 +3084,18 @@ pub fn ensure_backend_for_repo(
         provider: Some(match provider {
             VmProvider::Lima => "lima".to_string(),
             VmProvider::Wsl => "wsl".to_string(),
+            VmProvider::Container => "container".to_string(),
         }),
     })
 }
 
 pub fn stop_backend_for_repo(app: &AppHandle, repo_path: &Path) -> Result<(), String> {
-    let provider = vm_provider()?;
+    let provider = provider_for_mode(effective_backend_mode(app, Some(repo_path))?)?;
     let limactl = limactl_path(Some(app));
     let installed = match provider {
         VmProvider::Lima => limactl.is_some() || command_exists("limactl"),
         VmProvider::Wsl => check_wsl_available(),
+        VmProvider::Container => container_engine().is_some(),
     };
     if !installed {
         return Ok(());
@@ -2231,6 +3126,7 @@ pub fn stop_backend_for_repo(app: &AppHandle, repo_path: &Path) -> Result<(), St
         let running = match provider {
             VmProvider::Lima => lima_instance_running(&name, limactl.as_deref()),
             VmProvider::Wsl => wsl_instance_running(&name),
+            VmProvider::Container => host_container_running(&name) == Some(true),
         };
         if !running {
             continue;
@@ -2286,41 +3182,70 @@ pub fn stop_backend_for_repo(app: &AppHandle, repo_path: &Path) -> Result<(), St
     }
 }
 
-pub fn stop_all_repo_backends(app: &AppHandle) {
-    let provider = match vm_provider() {
-        Ok(value) => value,
-        Err(_) => return,
-    };
-    let limactl = limactl_path(Some(app));
-    let installed = match provider {
-        VmProvider::Lima => limactl.is_some() || command_exists("limactl"),
-        VmProvider::Wsl => check_wsl_available(),
-    };
-    if !installed {
-        return;
-    }
+/// Stops every running VM or host container that belongs to a repo and
+/// returns the names of the backends that were stopped.
+pub fn stop_all_repo_backends(app: &AppHandle) -> Vec<String> {
+    let mut stopped = Vec::new();
     let vms = match list_backend_vms_inner(app) {
         Ok(list) => list,
-        Err(_) => return,
+        Err(_) => return stopped,
     };
+    let limactl = limactl_path(Some(app));
     let _guard = match vm_lock() {
         Ok(guard) => guard,
-        Err(_) => return,
+        Err(_) => return stopped,
     };
-    for vm in vms {
-        if vm.repo_path.is_none() || vm.status == "stopped" {
-            continue;
+    for (provider, name) in backends_to_stop(vms) {
+        if stop_vm_inner(provider, &name, limactl.as_deref()).is_ok() {
+            stopped.push(name);
         }
-        let _ = stop_vm_inner(provider, &vm.name, limactl.as_deref());
+    }
+    stopped
+}
+
+/// Running repo backends from a `list_backend_vms_inner` listing, paired with
+/// the provider that has to stop them.
+fn backends_to_stop(vms: Vec<BackendVmInfo>) -> Vec<(VmProvider, String)> {
+    vms.into_iter()
+        .filter(|vm| vm.repo_path.is_some() && vm.status != "stopped")
+        .filter_map(|vm| provider_from_id(&vm.provider).map(|provider| (provider, vm.name)))
+        .collect()
+}
+
+/// Shutdown hook: stops repo VMs and host containers unless the user turned
+/// `stop_vm_on_exit` off.
+pub fn stop_repo_backends_on_exit(app: &AppHandle) {
+    if storage::get_stop_vm_on_exit(app).unwrap_or(true) {
+        stop_all_repo_backends(app);
     }
 }
 
+/// Lists the OS's VMs plus any labelled host containers. A missing VM tool is
+/// only an error when no container engine is installed either.
 fn list_backend_vms_inner(app: &AppHandle) -> Result<Vec<BackendVmInfo>, String> {
-    let provider = vm_provider()?;
-    match provider {
+    let vms = vm_provider().and_then(|provider| match provider {
         VmProvider::Lima => list_lima_vms(app),
         VmProvider::Wsl => list_wsl_vms(app),
+        VmProvider::Container => Ok(Vec::new()),
+    });
+    if container_engine().is_none() {
+        return vms;
     }
+    let mut all = vms.unwrap_or_else(|err| {
+        eprintln!("[falck][backend] VM listing skipped: {}", err);
+        Vec::new()
+    });
+    all.extend(list_host_containers()?);
+    Ok(all)
+}
+
+/// Provider for a listed backend: host containers are matched by name, so
+/// they take precedence over the OS's VM provider.
+fn provider_for_vm_name(name: &str) -> Result<VmProvider, String> {
+    if container_engine().is_some() && host_container_running(name).is_some() {
+        return Ok(VmProvider::Container);
+    }
+    vm_provider()
 }
 
 #[tauri::command]
@@ -2328,10 +3253,52 @@ pub async fn list_backend_vms(app: AppHandle) -> Result<Vec<BackendVmInfo>, Stri
     run_blocking(move || list_backend_vms_inner(&app)).await
 }
 
+#[tauri::command]
+pub async fn get_vm_resources(app: AppHandle) -> Result<VmResources, String> {
+    run_blocking(move || storage::get_vm_resources(&app)).await
+}
+
+/// Saves VM resource settings and returns the names of existing VMs that
+/// must be reset before the new values take effect.
+#[tauri::command]
+pub async fn set_vm_resources(
+    app: AppHandle,
+    resources: VmResources,
+) -> Result<Vec<String>, String> {
+    run_blocking(move || {
+        storage::set_vm_resources(&app, &resources)?;
+        if vm_provider()? != VmProvider::Lima {
+            return Ok(Vec::new());
+        }
+        let vms = list_lima_vms(&app)?;
+        Ok(vms
+            .into_iter()
+            .filter(|vm| lima_vm_needs_reset(&vm.name, &resources))
+            .map(|vm| vm.name)
+            .collect())
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn stop_all_backends(app: AppHandle) -> Result<Vec<String>, String> {
+    run_blocking(move || Ok(stop_all_repo_backends(&app))).await
+}
+
+#[tauri::command]
+pub async fn get_stop_vm_on_exit(app: AppHandle) -> Result<bool, String> {
+    run_blocking(move || storage::get_stop_vm_on_exit(&app)).await
+}
+
+#[tauri::command]
+pub async fn set_stop_vm_on_exit(app: AppHandle, enabled: bool) -> Result<(), String> {
+    run_blocking(move || storage::set_stop_vm_on_exit(&app, enabled)).await
+}
+
 #[tauri::command]
 pub async fn stop_backend_vm(app: AppHandle, name: String) -> Result<(), String> {
     run_blocking(move || {
-        let provider = vm_provider()?;
+        let provider = provider_for_vm_name(&name)?;
         let limactl = limactl_path(Some(&app));
         stop_vm(provider, &name, limactl.as_deref())
     })
@@ -2341,22 +3308,56 @@ pub async fn stop_backend_vm(app: AppHandle, name: String) -> Result<(), String>
 #[tauri::command]
 pub async fn delete_backend_vm(app: AppHandle, name: String) -> Result<(), String> {
     run_blocking(move || {
-        let provider = vm_provider()?;
+        let provider = provider_for_vm_name(&name)?;
         let limactl = limactl_path(Some(&app));
         delete_vm(provider, &name, limactl.as_deref())
     })
     .await
 }
 
-pub fn kill_vm_process(handle: &VmProcessHandle, pid: u32) -> Result<(), String> {
-    let script = format!("kill {}", pid);
-    let vm = VmContext {
+fn vm_context_for_handle(handle: &VmProcessHandle) -> VmContext {
+    VmContext {
         provider: handle.provider,
         name: handle.name.clone(),
         repo_path: PathBuf::new(),
         repo_root: "/".to_string(),
         limactl_path: handle.limactl_path.clone(),
-    };
+    }
+}
+
+/// Whether `pid` is still running inside the VM. A VM that cannot be reached
+/// counts as the process being gone; `vm_backend_running` tells the two apart.
+pub fn vm_process_alive(handle: &VmProcessHandle, pid: u32) -> bool {
+    let vm = vm_context_for_handle(handle);
+    let mut cmd = build_vm_command(&vm, &format!("kill -0 {}", pid));
+    cmd.stdout(Stdio::null()).stderr(Stdio::null());
+    match cmd.status() {
+        Ok(status) => status.success(),
+        Err(err) => {
+            eprintln!(
+                "[falck][backend] liveness check failed for {}: {}",
+                handle.name, err
+            );
+            true
+        }
+    }
+}
+
+/// Whether the VM or host container behind `handle` is running. The liveness
+/// watcher checks this before treating a missing process as a crash.
+pub fn vm_backend_running(handle: &VmProcessHandle) -> bool {
+    match handle.provider {
+        VmProvider::Lima => lima_instance_running(&handle.name, handle.limactl_path.as_deref()),
+        VmProvider::Wsl => wsl_instance_running(&handle.name),
+        VmProvider::Container => host_container_running(&handle.name) == Some(true),
+    }
+}
+
+pub fn kill_vm_process(handle: &VmProcessHandle, pid: u32) -> Result<(), String> {
+    // Launches run under setsid, so the pid is also the process group id.
+    // Fall back to the single pid for processes started without a group.
+    let script = format!("kill -TERM -{0} 2>/dev/null || kill {0}", pid);
+    let vm = vm_context_for_handle(handle);
     let mut cmd = build_vm_command(&vm, &script);
     let status = cmd
         .status()
@@ -2369,18 +3370,43 @@ pub fn kill_vm_process(handle: &VmProcessHandle, pid: u32) -> Result<(), String>
 }
 
 #[tauri::command]
-pub async fn get_backend_mode(app: AppHandle) -> Result<BackendMode, String> {
-    run_blocking(move || effective_backend_mode(&app)).await
+pub async fn get_backend_mode(
+    app: AppHandle,
+    repo_path: Option<String>,
+) -> Result<BackendMode, String> {
+    run_blocking(move || effective_backend_mode(&app, repo_path.as_deref().map(Path::new))).await
 }
 
 #[tauri::command]
-pub async fn set_backend_mode(app: AppHandle, mode: BackendMode) -> Result<(), String> {
+pub async fn set_backend_mode(
+    app: AppHandle,
+    mode: BackendMode,
+    repo_path: Option<String>,
+) -> Result<(), String> {
+    if mode == BackendMode::Container {
+        ensure_container_mode_supported()?;
+    }
     let handle = app.clone();
-    run_blocking(move || storage::set_backend_mode(&handle, mode)).await?;
-    let _ = app.emit("backend:mode", mode);
+    let event_repo_path = repo_path.clone();
+    run_blocking(move || {
+        storage::set_backend_mode(&handle, repo_path.as_deref().map(Path::new), mode)
+    })
+    .await?;
+    let _ = app.emit(
+        "backend:mode",
+        BackendModeEvent {
+            repo_path: event_repo_path,
+            mode,
+        },
+    );
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_supported_backend_modes() -> Result<Vec<BackendMode>, String> {
+    Ok(supported_backend_modes())
+}
+
 #[tauri::command]
 pub async fn check_virtualized_backend_prereq(
     app: AppHandle,
@@ -2391,8 +3417,14 @@ pub async fn check_virtualized_backend_prereq(
         let installed = match provider {
             VmProvider::Lima => limactl.is_some() || command_exists("limactl"),
             VmProvider::Wsl => check_wsl_available(),
+            VmProvider::Container => container_engine().is_some(),
         };
         let tool = tool_name(provider).to_string();
+        let version = if installed {
+            tool_version(provider, limactl.as_deref())
+        } else {
+            None
+        };
         let message = if installed {
             None
         } else {
@@ -2404,6 +3436,7 @@ pub async fn check_virtualized_backend_prereq(
         Ok(BackendPrereqStatus {
             installed,
             tool,
+            version,
             message,
         })
     })
@@ -2423,64 +3456,79 @@ pub async fn ensure_repo_backend(
 }
 
 #[tauri::command]
-pub async fn stop_repo_backend(app: AppHandle, repo_path: String) -> Result<(), String> {
+pub async fn run_backend_command(
+    app: AppHandle,
+    repo_path: String,
+    command: String,
+) -> Result<CommandOutput, String> {
     run_blocking(move || {
         let path = Path::new(&repo_path);
-        stop_backend_for_repo(&app, path)
+        if !repo_vm_exists(&app, path)? {
+            return Err(
+                "No VM exists for this repo yet. Launch an app or start the backend first."
+                    .to_string(),
+            );
+        }
+        run_in_backend(&app, path, &command)
     })
     .await
 }
 
 #[tauri::command]
-pub async fn delete_repo_backend(app: AppHandle, repo_path: String) -> Result<(), String> {
+pub async fn cancel_backend_operation(repo_path: String) -> Result<bool, String> {
+    Ok(request_cancel(Path::new(&repo_path)))
+}
+
+#[tauri::command]
+pub async fn get_backend_stats(app: AppHandle, repo_path: String) -> Result<BackendStats, String> {
+    run_blocking(move || read_backend_stats(&app, Path::new(&repo_path))).await
+}
+
+#[tauri::command]
+pub async fn get_vm_logs(app: AppHandle, repo_path: String) -> Result<String, String> {
+    run_blocking(move || read_vm_logs(&app, Path::new(&repo_path))).await
+}
+
+#[tauri::command]
+pub async fn get_backend_capabilities(
+    app: AppHandle,
+    repo_path: String,
+) -> Result<BackendCapabilities, String> {
+    run_blocking(move || read_backend_capabilities(&app, Path::new(&repo_path))).await
+}
+
+#[tauri::command]
+pub async fn stop_repo_backend(app: AppHandle, repo_path: String) -> Result<(), String> {
     run_blocking(move || {
         let path = Path::new(&repo_path);
-        let mode = effective_backend_mode(&app)?;
-        if mode == BackendMode::Host {
-            return Ok(());
-        }
-        let provider = vm_provider()?;
-        let limactl = limactl_path(Some(&app));
-        let name = vm_name_for_repo(path);
-        emit_vm_status(
-            Some(&app),
-            path,
-            Some(&name),
-            Some(provider),
-            "deleting",
-            "Deleting virtual machine",
-        );
-        match delete_vm(provider, &name, limactl.as_deref()) {
-            Ok(()) => {
-                emit_vm_status(
-                    Some(&app),
-                    path,
-                    Some(&name),
-                    Some(provider),
-                    "deleted",
-                    "VM deleted",
-                );
-                Ok(())
-            }
-            Err(err) => {
-                emit_vm_status(
-                    Some(&app),
-                    path,
-                    Some(&name),
-                    Some(provider),
-                    "error",
-                    &format!("Failed to delete VM: {err}"),
-                );
-                Err(err)
-            }
-        }
+        stop_backend_for_repo(&app, path)
     })
     .await
 }
 
-pub fn background_launch_script(command: &str) -> String {
+#[tauri::command]
+pub async fn delete_repo_backend(app: AppHandle, repo_path: String) -> Result<(), String> {
+    run_blocking(move || delete_backend_for_repo(&app, Path::new(&repo_path))).await
+}
+
+#[tauri::command]
+pub async fn reset_repo_backend(
+    app: AppHandle,
+    repo_path: String,
+) -> Result<BackendEnsureResult, String> {
+    run_blocking(move || reset_backend_for_repo(&app, Path::new(&repo_path))).await
+}
+
+pub fn background_launch_script(command: &str, log_path: Option<&str>) -> String {
     let escaped = shell_escape(command);
-    format!("nohup sh -c {} > /dev/null 2>&1 & echo $!", escaped)
+    let target = log_path
+        .map(shell_escape)
+        .unwrap_or_else(|| "/dev/null".to_string());
+    // Run in a new session so stopping the app can signal its whole tree.
+    format!(
+        "if command -v setsid >/dev/null 2>&1; then setsid nohup sh -c {0} > {1} 2>&1 & else nohup sh -c {0} > {1} 2>&1 & fi; echo $!",
+        escaped, target
+    )
 }
 
 pub fn extract_pid(output: &str) -> Result<u32, String> {
@@ -2491,3 +3539,42 @@ pub fn extract_pid(output: &str) -> Result<u32, String> {
         .ok_or_else(|| "Failed to determine VM process id.".to_string())?;
     Ok(pid)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backend(name: &str, provider: &str, status: &str, repo: Option<&str>) -> BackendVmInfo {
+        BackendVmInfo {
+            name: name.to_string(),
+            provider: provider.to_string(),
+            status: status.to_string(),
+            repo_path: repo.map(|value| value.to_string()),
+        }
+    }
+
+    #[test]
+    fn backends_to_stop_includes_container_mode_repos() {
+        let vms = vec![
+            backend("falck-web-1", "lima", "running", Some("/repos/web")),
+            backend("falck-api-2", "container", "running", Some("/repos/api")),
+        ];
+        assert_eq!(
+            backends_to_stop(vms),
+            vec![
+                (VmProvider::Lima, "falck-web-1".to_string()),
+                (VmProvider::Container, "falck-api-2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn backends_to_stop_skips_stopped_and_unowned_backends() {
+        let vms = vec![
+            backend("falck-api-2", "container", "stopped", Some("/repos/api")),
+            backend("default", "lima", "running", None),
+            backend("falck-odd-3", "unknown", "running", Some("/repos/odd")),
+        ];
+        assert!(backends_to_stop(vms).is_empty());
+    }
+}
@@ -1,14 +1,17 @@
 use anyhow::{bail, Context, Result as AnyhowResult};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus, Stdio};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager};
 
+use crate::backend;
 use crate::blocking::run_blocking;
+use crate::lima;
 use crate::storage::{self, StoredContainer};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -149,6 +152,8 @@ fn limactl_executable_name() -> &'static str {
 }
 
 const BUNDLED_LIMA_VERSION: &str = "2.0.3";
+const CAPTURED_LOG_LINES: usize = 200;
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(200);
 const FALCK_LIMA_TEMPLATE: &str = r#"
 images:
   - location: "https://cloud-images.ubuntu.com/releases/24.04/release/ubuntu-24.04-server-cloudimg-amd64.img"
@@ -437,7 +442,7 @@ pub(crate) fn limactl_path(app: &AppHandle) -> Option<PathBuf> {
     Some(location.path)
 }
 
-fn limactl_version(path: &Path) -> Option<String> {
+pub(crate) fn limactl_version(path: &Path) -> Option<String> {
     let output = Command::new(path).arg("--version").output().ok()?;
     if !output.status.success() {
         return None;
@@ -454,23 +459,49 @@ fn limactl_version(path: &Path) -> Option<String> {
 
 fn run_command_with_logs(
     app: &AppHandle,
-    mut command: Command,
+    command: Command,
     label: &str,
     ctx: &EventContext,
 ) -> AnyhowResult<ExitStatus> {
+    run_command_with_captured_logs(app, command, label, ctx).map(|(status, _)| status)
+}
+
+fn check_canceled() -> AnyhowResult<()> {
+    backend::check_canceled().map_err(|err| anyhow::anyhow!(err))
+}
+
+fn push_captured_line(captured: &Mutex<VecDeque<String>>, line: &str) {
+    let mut captured = captured.lock().unwrap_or_else(|err| err.into_inner());
+    if captured.len() >= CAPTURED_LOG_LINES {
+        captured.pop_front();
+    }
+    captured.push_back(line.to_string());
+}
+
+/// Streams output like `run_command_with_logs` and also returns the last
+/// lines of combined output so failures can report what went wrong.
+fn run_command_with_captured_logs(
+    app: &AppHandle,
+    mut command: Command,
+    label: &str,
+    ctx: &EventContext,
+) -> AnyhowResult<(ExitStatus, String)> {
     command.stdout(Stdio::piped()).stderr(Stdio::piped());
     let mut child = command.spawn().context("Failed to spawn command")?;
 
     let stdout = child.stdout.take();
     let stderr = child.stderr.take();
+    let captured = Arc::new(Mutex::new(VecDeque::new()));
 
     let app_stdout = app.clone();
     let ctx_stdout = ctx.clone();
     let label_stdout = label.to_string();
+    let captured_stdout = captured.clone();
     let out_handle = std::thread::spawn(move || {
         if let Some(out) = stdout {
             let reader = BufReader::new(out);
             for line in reader.lines().flatten() {
+                push_captured_line(&captured_stdout, &line);
                 let message = format!("[{}] {}", label_stdout, line);
                 emit_container_log(&app_stdout, &message, &ctx_stdout);
             }
@@ -480,157 +511,93 @@ fn run_command_with_logs(
     let app_stderr = app.clone();
     let ctx_stderr = ctx.clone();
     let label_stderr = label.to_string();
+    let captured_stderr = captured.clone();
     let err_handle = std::thread::spawn(move || {
         if let Some(err) = stderr {
             let reader = BufReader::new(err);
             for line in reader.lines().flatten() {
+                push_captured_line(&captured_stderr, &line);
                 let message = format!("[{}] ERROR: {}", label_stderr, line);
                 emit_container_log(&app_stderr, &message, &ctx_stderr);
             }
         }
     });
 
-    let status = child.wait().context("Command failed")?;
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Command failed")? {
+            break status;
+        }
+        if backend::cancel_requested() {
+            // The reader threads finish on their own once the pipes close.
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!("{}", backend::CANCELED_MESSAGE);
+        }
+        std::thread::sleep(CANCEL_POLL_INTERVAL);
+    };
     let _ = out_handle.join();
     let _ = err_handle.join();
-    Ok(status)
+    let output = captured
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .iter()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok((status, output))
 }
 
-fn parse_limactl_json(stdout: &[u8]) -> Option<serde_json::Value> {
-    let text = String::from_utf8_lossy(stdout);
-    let start = text.find(|ch| ch == '{' || ch == '[')?;
-    serde_json::from_str(&text[start..]).ok()
+fn failure_with_output(message: &str, output: &str) -> anyhow::Error {
+    let output = output.trim();
+    if output.is_empty() {
+        anyhow::anyhow!("{}", message)
+    } else {
+        anyhow::anyhow!("{}:\n{}", message, output)
+    }
 }
 
-fn ensure_vm_running(
-    app: &AppHandle,
-    limactl: &Path,
-    vm: &str,
-    ctx: &EventContext,
-) -> AnyhowResult<()> {
-    ensure_lima_guest_agents(app, limactl)?;
-    let template_path =
-        prepare_lima_environment(app).map_err(|err| anyhow::anyhow!(err))?;
-    let mut vm_exists = false;
-    let mut vm_running = false;
-
+fn lima_instance_state(limactl: &Path, vm: &str) -> lima::InstanceState {
     for args in [["list", "--json"], ["list", "--format=json"]] {
-        let output = Command::new(limactl).args(args).output().ok();
-        let Some(output) = output else {
+        let Ok(output) = lima::limactl_command(Some(limactl)).args(args).output() else {
             continue;
         };
         if !output.status.success() {
             continue;
         }
-        let Some(value) = parse_limactl_json(&output.stdout) else {
-            continue;
-        };
-        let mut entries: Vec<&serde_json::Value> = Vec::new();
-        if let Some(array) = value.as_array() {
-            entries.extend(array);
-        } else if let Some(array) = value.get("items").and_then(|value| value.as_array()) {
-            entries.extend(array);
-        } else if let Some(array) = value
-            .get("instances")
-            .and_then(|value| value.as_array())
-        {
-            entries.extend(array);
-        }
-
-        if entries.is_empty() {
-            continue;
-        }
-
-        for entry in entries {
-            let name = entry
-                .get("name")
-                .or_else(|| entry.get("Name"))
-                .and_then(|value| value.as_str());
-            if name != Some(vm) {
-                continue;
-            }
-            vm_exists = true;
-            let status = entry
-                .get("status")
-                .or_else(|| entry.get("Status"))
-                .or_else(|| entry.get("state"))
-                .or_else(|| entry.get("State"))
-                .and_then(|value| value.as_str())
-                .unwrap_or("")
-                .to_lowercase();
-            if status.contains("running") {
-                vm_running = true;
-            }
-            break;
-        }
-
-        if vm_exists {
-            break;
+        if let Ok(entries) = lima::parse_list_entries(&output.stdout) {
+            return lima::instance_state(&entries, vm);
         }
     }
+    lima::InstanceState::Missing
+}
 
-    if vm_exists && vm_running {
-        return Ok(());
-    }
-
-    if vm_exists {
-        emit_container_status(
-            app,
-            "starting",
-            &format!("Starting Lima VM '{}'", vm),
-            ctx,
-        );
-        let mut command = Command::new(limactl);
-        command.args(["start", "--tty=false", "--mount-writable", "--mount-inotify", vm]);
-        let status = run_command_with_logs(app, command, "vm-start", ctx)?;
-        if status.success() {
-            return Ok(());
-        }
-        bail!("Failed to start Lima VM '{}'", vm);
-    }
-
-    emit_container_status(
-        app,
-        "creating",
-        &format!("Creating Lima VM '{}'", vm),
-        ctx,
-    );
-    let mut command = Command::new(limactl);
-    command.args([
-        "create",
-        "--name",
-        vm,
-        "--containerd=system",
-        "--tty=false",
-        "--mount-writable",
-        "--mount-inotify",
-    ]);
-    command.arg(&template_path);
-    let status = run_command_with_logs(app, command, "vm-create", ctx)?;
-    if !status.success() {
-        emit_container_status(
-            app,
-            "starting",
-            &format!("Starting Lima VM '{}'", vm),
-            ctx,
-        );
-        let mut command = Command::new(limactl);
-        command.args(["start", "--tty=false", vm]);
-        let status = run_command_with_logs(app, command, "vm-start", ctx)?;
-        if status.success() {
-            return Ok(());
+fn ensure_vm_running(
+    app: &AppHandle,
+    limactl: &Path,
+    vm: &str,
+    ctx: &EventContext,
+) -> AnyhowResult<()> {
+    check_canceled()?;
+    ensure_lima_guest_agents(app, limactl)?;
+    let template_path = prepare_lima_environment(app).map_err(|err| anyhow::anyhow!(err))?;
+
+    match lima_instance_state(limactl, vm) {
+        lima::InstanceState::Running => return Ok(()),
+        lima::InstanceState::Stopped => {}
+        lima::InstanceState::Missing => {
+            emit_container_status(app, "creating", &format!("Creating Lima VM '{}'", vm), ctx);
+            let command = lima::create_command(Some(limactl), vm, Some(&template_path), &[]);
+            let status = run_command_with_logs(app, command, "vm-create", ctx)?;
+            if !status.success() && lima_instance_state(limactl, vm) == lima::InstanceState::Missing
+            {
+                bail!("Failed to create Lima VM '{}'", vm);
+            }
         }
-        bail!("Failed to create Lima VM '{}'", vm);
     }
 
-    emit_container_status(
-        app,
-        "starting",
-        &format!("Starting Lima VM '{}'", vm),
-        ctx,
-    );
-    let mut command = Command::new(limactl);
-    command.args(["start", "--tty=false", "--mount-writable", "--mount-inotify", vm]);
+    check_canceled()?;
+    emit_container_status(app, "starting", &format!("Starting Lima VM '{}'", vm), ctx);
+    let command = lima::start_command(Some(limactl), vm, &[]);
     let status = run_command_with_logs(app, command, "vm-start", ctx)?;
     if status.success() {
         return Ok(());
@@ -683,17 +650,7 @@ fn ensure_mount_writable(
 
     let start_status = run_command_with_logs(
         app,
-        {
-            let mut command = Command::new(limactl);
-            command.args([
-                "start",
-                "--tty=false",
-                "--mount-writable",
-                "--mount-inotify",
-                vm,
-            ]);
-            command
-        },
+        lima::start_command(Some(limactl), vm, &[]),
         "vm-start",
         ctx,
     )?;
@@ -727,6 +684,49 @@ fn nerdctl_command(limactl: &Path, vm: &str, args: &[String]) -> Command {
     command
 }
 
+/// Whether a launched container is still running. A container that is gone,
+/// or whose VM is stopped, counts as not running; failing to reach limactl at
+/// all does not, so a transient error is not reported as a crash.
+pub(crate) fn container_running(limactl: &Path, handle: &ContainerHandle) -> bool {
+    let args = vec![
+        "inspect".to_string(),
+        "-f".to_string(),
+        "{{.State.Running}}".to_string(),
+        handle.name.clone(),
+    ];
+    match nerdctl_command(limactl, &handle.vm, &args).output() {
+        Ok(output) => {
+            output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "true"
+        }
+        Err(err) => {
+            eprintln!(
+                "[falck][containers] liveness check failed for {}: {}",
+                handle.name, err
+            );
+            true
+        }
+    }
+}
+
+/// Whether the Lima VM hosting a launched container is running.
+pub(crate) fn container_vm_running(limactl: &Path, handle: &ContainerHandle) -> bool {
+    lima_instance_state(limactl, &handle.vm) == lima::InstanceState::Running
+}
+
+pub(crate) fn stop_launched_container(
+    limactl: &Path,
+    handle: &ContainerHandle,
+) -> Result<(), String> {
+    let args = vec!["stop".to_string(), handle.name.clone()];
+    let status = nerdctl_command(limactl, &handle.vm, &args)
+        .status()
+        .map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("Failed to stop container '{}'", handle.name));
+    }
+    Ok(())
+}
+
 fn status_to_state(status: Option<&str>) -> String {
     let Some(status) = status else {
         return "unknown".to_string();
@@ -772,6 +772,31 @@ pub async fn check_lima_installed(app: AppHandle) -> Result<LimaStatus, String>
     .await
 }
 
+pub(crate) fn container_mount_arg(mount: &ContainerMountSpec) -> String {
+    let mut mount_value = match &mount.source {
+        ContainerMountSource::Bind(path) => {
+            format!("{}:{}", path.display(), mount.target)
+        }
+        ContainerMountSource::Volume(volume) => {
+            format!("{}:{}", volume, mount.target)
+        }
+    };
+    if let Some(mode) = &mount.mode {
+        if !mode.trim().is_empty() {
+            let trimmed = mode.trim();
+            let filtered = match trimmed {
+                "delegated" | "cached" => None,
+                _ => Some(trimmed),
+            };
+            if let Some(value) = filtered {
+                mount_value.push(':');
+                mount_value.push_str(value);
+            }
+        }
+    }
+    mount_value
+}
+
 pub fn launch_container(app: &AppHandle, spec: ContainerLaunchSpec) -> AnyhowResult<ContainerHandle> {
     let limactl = limactl_path(app).context(
         "Lima is unavailable. Reinstall Falck or use a build that bundles Lima.",
@@ -783,6 +808,7 @@ pub fn launch_container(app: &AppHandle, spec: ContainerLaunchSpec) -> AnyhowRes
         container: Some(spec.name.clone()),
     };
     ensure_vm_running(app, &limactl, &spec.vm, &ctx)?;
+    check_canceled()?;
 
     emit_container_status(
         app,
@@ -813,16 +839,17 @@ pub fn launch_container(app: &AppHandle, spec: ContainerLaunchSpec) -> AnyhowRes
         spec.dockerfile_path.to_string_lossy().to_string(),
         spec.context_dir.to_string_lossy().to_string(),
     ];
-    let build_status = run_command_with_logs(
+    let (build_status, build_output) = run_command_with_captured_logs(
         app,
         nerdctl_command(&limactl, &spec.vm, &build_args),
         "build",
         &ctx,
     )?;
     if !build_status.success() {
-        bail!("Container build failed");
+        return Err(failure_with_output("Container build failed", &build_output));
     }
 
+    check_canceled()?;
     emit_container_status(
         app,
         "starting",
@@ -848,29 +875,8 @@ pub fn launch_container(app: &AppHandle, spec: ContainerLaunchSpec) -> AnyhowRes
     }
 
     for mount in &spec.mounts {
-        let mut mount_value = match &mount.source {
-            ContainerMountSource::Bind(path) => {
-                format!("{}:{}", path.display(), mount.target)
-            }
-            ContainerMountSource::Volume(volume) => {
-                format!("{}:{}", volume, mount.target)
-            }
-        };
-        if let Some(mode) = &mount.mode {
-            if !mode.trim().is_empty() {
-                let trimmed = mode.trim();
-                let filtered = match trimmed {
-                    "delegated" | "cached" => None,
-                    _ => Some(trimmed),
-                };
-                if let Some(value) = filtered {
-                    mount_value.push(':');
-                    mount_value.push_str(value);
-                }
-            }
-        }
         run_args.push("-v".to_string());
-        run_args.push(mount_value);
+        run_args.push(container_mount_arg(mount));
     }
 
     for (key, value) in &spec.env {
@@ -880,14 +886,17 @@ pub fn launch_container(app: &AppHandle, spec: ContainerLaunchSpec) -> AnyhowRes
 
     run_args.push(spec.image.clone());
 
-    let run_status = run_command_with_logs(
+    let (run_status, run_output) = run_command_with_captured_logs(
         app,
         nerdctl_command(&limactl, &spec.vm, &run_args),
         "run",
         &ctx,
     )?;
     if !run_status.success() {
-        bail!("Container failed to start");
+        return Err(failure_with_output(
+            "Container failed to start",
+            &run_output,
+        ));
     }
 
     let log_app = app.clone();
@@ -1098,6 +1107,8 @@ pub async fn stop_container(
     vm: String,
     name: String,
 ) -> Result<String, String> {
+    // Stopped on purpose, so the liveness watcher must not report it.
+    crate::falck::forget_container_app(&app, &id);
     run_blocking(move || {
         let limactl = limactl_path(&app).ok_or_else(|| {
             "Lima is unavailable. Reinstall Falck or use a build that bundles Lima."
@@ -1149,6 +1160,7 @@ pub async fn delete_container(
     vm: String,
     name: String,
 ) -> Result<String, String> {
+    crate::falck::forget_container_app(&app, &id);
     run_blocking(move || {
         let limactl = limactl_path(&app).ok_or_else(|| {
             "Lima is unavailable. Reinstall Falck or use a build that bundles Lima."
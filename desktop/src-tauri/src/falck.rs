@@ -3,32 +3,146 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use semver::Version;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::env;
-use std::io::Read;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Component, Path, PathBuf};
-use std::process::{Command, ExitStatus, Stdio};
-use std::sync::{Mutex, OnceLock};
+use std::process::{ChildStderr, ChildStdout, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_stronghold::stronghold::Stronghold;
 
 use crate::blocking::{run_blocking, run_blocking_value};
-use crate::backend::{self, BackendContext, BackendProcess, VmProcessHandle};
+use crate::backend::{self, BackendContext, BackendProcess, VmContext, VmProcessHandle};
+use crate::storage;
+
+type SecretsByRepo = HashMap<String, HashMap<String, String>>;
 
 lazy_static! {
-    static ref SECRETS_STORE: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    static ref SECRETS_STORE: Mutex<SecretsByRepo> = Mutex::new(HashMap::new());
+    static ref APP_LOGS: Mutex<HashMap<u32, VecDeque<String>>> = Mutex::new(HashMap::new());
+    static ref AUTO_RESTARTS: Mutex<HashMap<(PathBuf, String), (u32, Instant)>> =
+        Mutex::new(HashMap::new());
 }
 
+static SECRETS_VAULT: OnceLock<Stronghold> = OnceLock::new();
+const SECRETS_VAULT_FILE: &str = "secrets.hold";
+const SECRETS_VAULT_CLIENT: &[u8] = b"falck-secrets";
+const SECRETS_VAULT_RECORD: &[u8] = b"secrets";
+
 static SHELL_ENV_CACHE: OnceLock<Option<HashMap<String, String>>> = OnceLock::new();
 const VM_ENV_TIMEOUT_SECS: u32 = 20;
+const DEFAULT_READY_TIMEOUT_SECS: u32 = 60;
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const APP_LOG_CAPACITY: usize = 1000;
+const SETUP_OUTPUT_TAIL_LINES: usize = 40;
+const SETUP_RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+const LIVENESS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const AUTO_RESTART_LIMIT: u32 = 3;
+const AUTO_RESTART_WINDOW: Duration = Duration::from_secs(60);
 
 #[derive(Debug, Clone)]
 pub struct RunningFalckApp {
     pub process: BackendProcess,
+    pub repo_path: PathBuf,
+    pub app_id: String,
 }
 
 pub struct FalckProcessState(pub Mutex<HashMap<u32, RunningFalckApp>>);
 
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningAppInfo {
+    pub handle: u32,
+    pub repo_path: String,
+    pub app_id: String,
+    /// "host", "lima", "wsl" or "container".
+    pub backend: String,
+    pub pid: u32,
+    /// Lima/WSL instance or container the process runs in.
+    pub instance: Option<String>,
+}
+
+/// Where the output of a freshly launched process can be observed.
+pub enum LaunchOutput {
+    Pipes {
+        stdout: ChildStdout,
+        stderr: ChildStderr,
+    },
+    VmLog {
+        vm: VmContext,
+        path: String,
+    },
+}
+
+pub struct LaunchedProcess {
+    pub process: BackendProcess,
+    pub output: Option<LaunchOutput>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AppReadyEvent {
+    repo_path: String,
+    app_id: String,
+    handle: u32,
+    url: Option<String>,
+    ready: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AppExitedEvent {
+    repo_path: String,
+    app_id: String,
+    handle: u32,
+    restarting: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AppLogEvent {
+    repo_path: String,
+    app_id: String,
+    handle: u32,
+    stream: String,
+    line: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SetupProgressEvent {
+    repo_path: String,
+    app_id: String,
+    step_name: String,
+    index: usize,
+    total: usize,
+    status: String,
+    stderr: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SetupAppProgressEvent {
+    repo_path: String,
+    app_id: String,
+    index: usize,
+    total: usize,
+    status: String,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ReadyProbe {
+    signal: Option<String>,
+    port: Option<u16>,
+    url: Option<String>,
+    timeout: Duration,
+}
+
 impl Default for FalckProcessState {
     fn default() -> Self {
         Self(Mutex::new(HashMap::new()))
@@ -60,9 +174,205 @@ fn backend_process_pid(process: &BackendProcess) -> u32 {
             let hash = fnv1a_hash_u32(&input);
             0x8000_0000 | hash
         }
+        BackendProcess::Container { handle, .. } => container_app_handle(&handle.id),
     }
 }
 
+fn container_app_handle(container_id: &str) -> u32 {
+    0x8000_0000 | fnv1a_hash_u32(&format!("container:{}", container_id))
+}
+
+/// Drops a container app from the running set without stopping it, for
+/// callers that stop or remove the container themselves.
+pub fn forget_container_app(app: &AppHandle, container_id: &str) {
+    let state = app.state::<FalckProcessState>();
+    unregister_running_app(&state, container_app_handle(container_id));
+}
+
+fn running_app_info(handle: u32, app: &RunningFalckApp) -> RunningAppInfo {
+    let (backend, pid, instance) = match &app.process {
+        BackendProcess::Host { pid } => ("host", *pid, None),
+        BackendProcess::Virtualized { pid, vm } => {
+            let backend = match vm.provider {
+                backend::VmProvider::Lima => "lima",
+                backend::VmProvider::Wsl => "wsl",
+                backend::VmProvider::Container => "container",
+            };
+            (backend, *pid, Some(vm.name.clone()))
+        }
+        BackendProcess::Container { handle, .. } => ("container", 0, Some(handle.name.clone())),
+    };
+    RunningAppInfo {
+        handle,
+        repo_path: app.repo_path.to_string_lossy().to_string(),
+        app_id: app.app_id.clone(),
+        backend: backend.to_string(),
+        pid,
+        instance,
+    }
+}
+
+pub fn list_running(state: &FalckProcessState) -> Vec<RunningAppInfo> {
+    let guard = match state.0.lock() {
+        Ok(guard) => guard,
+        Err(err) => err.into_inner(),
+    };
+    let mut apps: Vec<RunningAppInfo> = guard
+        .iter()
+        .map(|(handle, app)| running_app_info(*handle, app))
+        .collect();
+    apps.sort_by(|a, b| a.repo_path.cmp(&b.repo_path).then(a.app_id.cmp(&b.app_id)));
+    apps
+}
+
+fn process_alive(process: &BackendProcess) -> bool {
+    match process {
+        BackendProcess::Host { pid } => host_process_alive(*pid),
+        BackendProcess::Virtualized { pid, vm } => backend::vm_process_alive(vm, *pid),
+        BackendProcess::Container { handle, limactl } => {
+            crate::containers::container_running(limactl, handle)
+        }
+    }
+}
+
+/// Whether the VM or container runtime an app runs in is up. Host apps have no
+/// separate backend.
+fn backend_running(process: &BackendProcess) -> bool {
+    match process {
+        BackendProcess::Host { .. } => true,
+        BackendProcess::Virtualized { vm, .. } => backend::vm_backend_running(vm),
+        BackendProcess::Container { handle, limactl } => {
+            crate::containers::container_vm_running(limactl, handle)
+        }
+    }
+}
+
+fn host_process_alive(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        let filter = format!("PID eq {}", pid);
+        match Command::new("tasklist")
+            .args(["/FI", &filter, "/NH"])
+            .output()
+        {
+            Ok(output) => String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()),
+            Err(_) => true,
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        match Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+        {
+            Ok(status) => status.success(),
+            Err(_) => true,
+        }
+    }
+}
+
+fn app_auto_restart(repo_path: &Path, app_id: &str) -> bool {
+    load_config(repo_path)
+        .ok()
+        .and_then(|config| config.applications.into_iter().find(|app| app.id == app_id))
+        .and_then(|app| app.launch.auto_restart)
+        .unwrap_or(false)
+}
+
+/// Counts restarts per app and refuses once an app has crashed
+/// `AUTO_RESTART_LIMIT` times within `AUTO_RESTART_WINDOW`.
+fn take_restart_budget(repo_path: &Path, app_id: &str) -> bool {
+    let mut guard = match AUTO_RESTARTS.lock() {
+        Ok(guard) => guard,
+        Err(err) => err.into_inner(),
+    };
+    let key = (repo_path.to_path_buf(), app_id.to_string());
+    let now = Instant::now();
+    let entry = guard.entry(key).or_insert((0, now));
+    if now.duration_since(entry.1) > AUTO_RESTART_WINDOW {
+        *entry = (0, now);
+    }
+    if entry.0 >= AUTO_RESTART_LIMIT {
+        return false;
+    }
+    entry.0 += 1;
+    true
+}
+
+/// Reports an app whose process is gone. When its backend was stopped the app
+/// is only marked stopped: restarting it would boot the VM the user just
+/// stopped.
+fn handle_app_exit(app: &AppHandle, handle: u32, running: RunningFalckApp, backend_up: bool) {
+    let repo_path = running.repo_path.to_string_lossy().to_string();
+    let restarting = backend_up
+        && app_auto_restart(&running.repo_path, &running.app_id)
+        && take_restart_budget(&running.repo_path, &running.app_id);
+    eprintln!(
+        "[falck][watch] {} exited{}",
+        running.app_id,
+        if !backend_up {
+            " (backend stopped)"
+        } else if restarting {
+            ", restarting"
+        } else {
+            ""
+        }
+    );
+    let _ = app.emit(
+        "falck:app-exited",
+        AppExitedEvent {
+            repo_path: repo_path.clone(),
+            app_id: running.app_id.clone(),
+            handle,
+            restarting,
+        },
+    );
+    if !restarting {
+        return;
+    }
+    let app = app.clone();
+    let app_id = running.app_id;
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<FalckProcessState>();
+        if let Err(err) = launch_app_by_id(&app, &state, repo_path, app_id.clone()).await {
+            eprintln!("[falck][watch] failed to restart {}: {}", app_id, err);
+        }
+    });
+}
+
+/// Polls every registered app and reports the ones whose process is gone.
+/// Apps stopped through Falck are unregistered before they are killed, so
+/// only unexpected exits are reported.
+pub fn start_liveness_watcher(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(LIVENESS_POLL_INTERVAL);
+        let snapshot: Vec<(u32, RunningFalckApp)> = {
+            let state = app.state::<FalckProcessState>();
+            let guard = match state.0.lock() {
+                Ok(guard) => guard,
+                Err(err) => err.into_inner(),
+            };
+            guard
+                .iter()
+                .map(|(handle, running)| (*handle, running.clone()))
+                .collect()
+        };
+        for (handle, running) in snapshot {
+            if process_alive(&running.process) {
+                continue;
+            }
+            let backend_up = backend_running(&running.process);
+            let state = app.state::<FalckProcessState>();
+            if let Some(running) = unregister_running_app(&state, handle) {
+                handle_app_exit(&app, handle, running, backend_up);
+            }
+        }
+    });
+}
+
 fn fnv1a_hash_u32(value: &str) -> u32 {
     let mut hash: u32 = 0x811c9dc5;
     for byte in value.as_bytes() {
@@ -78,6 +388,10 @@ fn kill_backend_process(process: BackendProcess) -> Result<()> {
         BackendProcess::Virtualized { pid, vm } => {
             backend::kill_vm_process(&vm, pid).map_err(|err| anyhow!(err))
         }
+        BackendProcess::Container { handle, limactl } => {
+            crate::containers::stop_launched_container(&limactl, &handle)
+                .map_err(|err| anyhow!(err))
+        }
     }
 }
 
@@ -125,6 +439,7 @@ pub struct RepositoryConfig {
     pub default_branch: Option<String>,
     pub protect_default_branch: Option<bool>,
     pub branch_prefix: Option<String>,
+    pub env_file: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -206,6 +521,8 @@ pub struct SetupStep {
     pub silent: Option<bool>,
     pub optional: Option<bool>,
     pub only_if: Option<String>,
+    pub retries: Option<u32>,
+    pub retry_delay_secs: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -246,6 +563,7 @@ pub struct LaunchConfig {
     pub env: Option<HashMap<String, String>>,
     pub ports: Option<Vec<u16>>,
     pub container: Option<ContainerLaunchConfig>,
+    pub auto_restart: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -298,6 +616,13 @@ pub struct AppGroup {
     pub apps: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidationIssue {
+    pub severity: String,
+    pub message: String,
+    pub app_id: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PrerequisiteCheckResult {
     pub name: String,
@@ -310,27 +635,340 @@ pub struct PrerequisiteCheckResult {
 }
 
 // ============================================================================
-// Config Loading
+// App Types
 // ============================================================================
 
-pub fn load_config(repo_path: &Path) -> Result<FalckConfig> {
-    let config_path = repo_path.join(".falck").join("config.yaml");
-    if !config_path.exists() {
-        bail!("No .falck/config.yaml found in repository");
+#[derive(Debug, Clone, Copy)]
+struct AppTypeDefaults {
+    launch_command: Option<&'static str>,
+    port: Option<u16>,
+}
+
+/// Launch defaults for the application types Falck recognizes. Returns
+/// `None` for unknown types.
+fn app_type_defaults(app_type: &str) -> Option<AppTypeDefaults> {
+    let (launch_command, port) = match app_type.trim().to_lowercase().as_str() {
+        "web" => (None, None),
+        "astro" => (Some("bun run dev"), Some(4321)),
+        "vite" => (Some("npm run dev"), Some(5173)),
+        "next" | "nextjs" => (Some("npm run dev"), Some(3000)),
+        "node" => (Some("npm start"), None),
+        "static" => (Some("npx --yes serve -l 3000 ."), Some(3000)),
+        "docker" => (Some("docker compose up"), None),
+        _ => return None,
+    };
+    Some(AppTypeDefaults {
+        launch_command,
+        port,
+    })
+}
+
+/// Fills in `launch.command` from the app type when the config leaves it
+/// empty. Container apps are launched from their Dockerfile and are left
+/// untouched.
+fn apply_app_type_defaults(config: &mut FalckConfig) {
+    for app in &mut config.applications {
+        if app.launch.container.is_some() {
+            continue;
+        }
+        let command = app.launch.command.as_deref().unwrap_or("");
+        if !command.trim().is_empty() {
+            continue;
+        }
+        if let Some(command) = app_type_defaults(&app.app_type).and_then(|d| d.launch_command) {
+            app.launch.command = Some(command.to_string());
+        }
     }
+}
 
-    let content = std::fs::read_to_string(&config_path).context("Failed to read config.yaml")?;
+// ============================================================================
+// Config Loading
+// ============================================================================
 
-    let config: FalckConfig =
-        serde_yaml::from_str(&content).context("Failed to parse config.yaml")?;
+pub fn load_config(repo_path: &Path) -> Result<FalckConfig> {
+    let falck_dir = repo_path.join(".falck");
+    let yaml_path = falck_dir.join("config.yaml");
+    let json_path = falck_dir.join("config.json");
+
+    let mut config: FalckConfig = if yaml_path.exists() {
+        if json_path.exists() {
+            eprintln!(
+                "[falck][config] both config.yaml and config.json found in {}, using config.yaml",
+                falck_dir.display()
+            );
+        }
+        let content =
+            std::fs::read_to_string(&yaml_path).context("Failed to read config.yaml")?;
+        serde_yaml::from_str(&content).context("Failed to parse config.yaml")?
+    } else if json_path.exists() {
+        let content =
+            std::fs::read_to_string(&json_path).context("Failed to read config.json")?;
+        serde_json::from_str(&content).context("Failed to parse config.json")?
+    } else {
+        bail!("No .falck/config.yaml or .falck/config.json found in repository");
+    };
 
     if config.version != "1.0" {
         bail!("Unsupported config version: {}", config.version);
     }
 
+    apply_app_type_defaults(&mut config);
     Ok(config)
 }
 
+fn scaffold_launch_defaults(app_type: &str) -> (&'static str, Option<u16>) {
+    match app_type_defaults(app_type) {
+        Some(AppTypeDefaults {
+            launch_command: Some(command),
+            port,
+        }) => (command, port),
+        _ => ("npm run dev", Some(3000)),
+    }
+}
+
+fn scaffold_app_id(app_name: &str) -> String {
+    let mut id = String::new();
+    for ch in app_name.trim().chars() {
+        if ch.is_ascii_alphanumeric() {
+            id.push(ch.to_ascii_lowercase());
+        } else if !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    let id = id.trim_end_matches('-').to_string();
+    if id.is_empty() {
+        "app".to_string()
+    } else {
+        id
+    }
+}
+
+fn strip_yaml_nulls(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            map.retain(|_, entry| !entry.is_null());
+            for (_, entry) in map.iter_mut() {
+                strip_yaml_nulls(entry);
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                strip_yaml_nulls(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Writes a minimal single-app `.falck/config.yaml`. An existing config is
+/// only replaced when `force` is set.
+pub fn scaffold_config(
+    repo_path: &Path,
+    app_name: &str,
+    app_type: &str,
+    force: bool,
+) -> Result<PathBuf> {
+    let falck_dir = repo_path.join(".falck");
+    let yaml_path = falck_dir.join("config.yaml");
+    let json_path = falck_dir.join("config.json");
+    if !force && (yaml_path.exists() || json_path.exists()) {
+        bail!("A Falck config already exists in this repository");
+    }
+
+    let app_type = app_type.trim().to_lowercase();
+    let (command, port) = scaffold_launch_defaults(&app_type);
+    let access = port.map(|port| AccessConfig {
+        access_type: "http".to_string(),
+        url: Some(format!("http://localhost:{}", port)),
+        open_browser: Some(true),
+        port: Some(port),
+        ready_signal: None,
+    });
+    let name = if app_name.trim().is_empty() {
+        "App".to_string()
+    } else {
+        app_name.trim().to_string()
+    };
+    let app_id = scaffold_app_id(&name);
+    let config = FalckConfig {
+        version: "1.0".to_string(),
+        metadata: Some(ConfigMetadata {
+            name: Some(name.clone()),
+            description: None,
+            author: None,
+            created: None,
+            updated: None,
+        }),
+        repository: None,
+        applications: vec![Application {
+            id: app_id.clone(),
+            name,
+            app_type,
+            description: None,
+            root: ".".to_string(),
+            assets: None,
+            prerequisites: Some(Vec::new()),
+            secrets: Some(Vec::new()),
+            setup: None,
+            launch: LaunchConfig {
+                command: Some(command.to_string()),
+                description: None,
+                timeout: None,
+                access,
+                env: None,
+                ports: port.map(|port| vec![port]),
+                container: None,
+                auto_restart: None,
+            },
+            cleanup: None,
+        }],
+        global_env: None,
+        install_order: None,
+        launch_order: Some(vec![app_id]),
+        groups: None,
+    };
+
+    let mut value = serde_yaml::to_value(&config).context("Failed to serialize config")?;
+    strip_yaml_nulls(&mut value);
+    let content = serde_yaml::to_string(&value).context("Failed to serialize config")?;
+    std::fs::create_dir_all(&falck_dir).context("Failed to create .falck directory")?;
+    std::fs::write(&yaml_path, content).context("Failed to write config.yaml")?;
+    if force && json_path.exists() {
+        // load_config prefers yaml, but a stale json would keep triggering the
+        // "both found" warning.
+        std::fs::remove_file(&json_path).context("Failed to remove config.json")?;
+    }
+    Ok(yaml_path)
+}
+
+fn validation_error(message: String, app_id: Option<&str>) -> ValidationIssue {
+    ValidationIssue {
+        severity: "error".to_string(),
+        message,
+        app_id: app_id.map(str::to_string),
+    }
+}
+
+fn validation_warning(message: String, app_id: Option<&str>) -> ValidationIssue {
+    ValidationIssue {
+        severity: "warning".to_string(),
+        message,
+        app_id: app_id.map(str::to_string),
+    }
+}
+
+fn check_app_references(
+    issues: &mut Vec<ValidationIssue>,
+    known_ids: &[&str],
+    ids: &[String],
+    source: &str,
+) {
+    for id in ids {
+        if !known_ids.contains(&id.as_str()) {
+            issues.push(validation_error(
+                format!("{} references unknown application '{}'", source, id),
+                None,
+            ));
+        }
+    }
+}
+
+fn app_conditions(app: &Application) -> Vec<(String, &str)> {
+    let mut conditions = Vec::new();
+    if let Some(prereqs) = &app.prerequisites {
+        for prereq in prereqs {
+            let options = prereq.install.as_ref().and_then(|install| install.options.as_ref());
+            for option in options.into_iter().flatten() {
+                if let Some(condition) = &option.only_if {
+                    conditions.push((
+                        format!("install option '{}' of '{}'", option.name, prereq.name),
+                        condition.as_str(),
+                    ));
+                }
+            }
+        }
+    }
+    if let Some(setup) = &app.setup {
+        for step in setup.steps.iter().flatten() {
+            if let Some(condition) = &step.only_if {
+                conditions.push((format!("setup step '{}'", step.name), condition.as_str()));
+            }
+        }
+        if let Some(condition) = setup.check.as_ref().and_then(|check| check.only_if.as_ref()) {
+            conditions.push(("setup check".to_string(), condition.as_str()));
+        }
+    }
+    if let Some(cleanup) = &app.cleanup {
+        for step in cleanup.steps.iter().flatten() {
+            if let Some(condition) = &step.only_if {
+                conditions.push((format!("cleanup step '{}'", step.name), condition.as_str()));
+            }
+        }
+    }
+    conditions
+}
+
+pub fn validate_config(repo_path: &Path) -> Vec<ValidationIssue> {
+    let config = match load_config(repo_path) {
+        Ok(config) => config,
+        Err(err) => return vec![validation_error(format!("{:#}", err), None)],
+    };
+
+    let mut issues = Vec::new();
+    let mut known_ids: Vec<&str> = Vec::new();
+    for app in &config.applications {
+        if known_ids.contains(&app.id.as_str()) {
+            issues.push(validation_error(
+                format!("Duplicate application id '{}'", app.id),
+                Some(&app.id),
+            ));
+        } else {
+            known_ids.push(&app.id);
+        }
+
+        if app_type_defaults(&app.app_type).is_none() {
+            issues.push(validation_warning(
+                format!(
+                    "Unknown application type '{}'; no launch defaults will be applied",
+                    app.app_type
+                ),
+                Some(&app.id),
+            ));
+        }
+
+        if !get_app_root(repo_path, app).exists() {
+            issues.push(validation_error(
+                format!("Application root '{}' does not exist", app.root),
+                Some(&app.id),
+            ));
+        }
+
+        for (location, condition) in app_conditions(app) {
+            if let Err(err) = tokenize(condition) {
+                issues.push(validation_error(
+                    format!("Invalid only_if in {}: {}", location, err),
+                    Some(&app.id),
+                ));
+            }
+        }
+    }
+
+    if let Some(order) = &config.launch_order {
+        check_app_references(&mut issues, &known_ids, order, "launch_order");
+    }
+    if let Some(order) = &config.install_order {
+        check_app_references(&mut issues, &known_ids, order, "install_order");
+    }
+    if let Some(groups) = &config.groups {
+        for group in groups {
+            let source = format!("Group '{}'", group.name);
+            check_app_references(&mut issues, &known_ids, &group.apps, &source);
+        }
+    }
+
+    issues
+}
+
 pub fn get_app_root(repo_path: &Path, app: &Application) -> PathBuf {
     if app.root == "." {
         repo_path.to_path_buf()
@@ -482,53 +1120,160 @@ pub fn get_app_secrets(app: &Application) -> Vec<Secret> {
     app.secrets.clone().unwrap_or_default()
 }
 
-pub fn check_app_secrets_satisfied(app: &Application) -> bool {
+fn secrets_repo_key(repo_path: &Path) -> String {
+    std::fs::canonicalize(repo_path)
+        .unwrap_or_else(|_| repo_path.to_path_buf())
+        .to_string_lossy()
+        .trim_end_matches(['/', '\\'])
+        .to_string()
+}
+
+/// Unlocks the on-disk Stronghold vault and caches its decrypted contents in
+/// `SECRETS_STORE`. Until this succeeds, secrets are kept in memory only.
+pub fn init_secrets_vault(app: &AppHandle) -> Result<(), String> {
+    let data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+    let key = storage::get_or_create_secrets_vault_key(app)?;
+    let stronghold =
+        Stronghold::new(data_dir.join(SECRETS_VAULT_FILE), key).map_err(|e| e.to_string())?;
+    let client = stronghold
+        .load_client(SECRETS_VAULT_CLIENT)
+        .or_else(|_| stronghold.create_client(SECRETS_VAULT_CLIENT))
+        .map_err(|e| e.to_string())?;
+    let snapshot = client
+        .store()
+        .get(SECRETS_VAULT_RECORD)
+        .map_err(|e| e.to_string())?;
+    if let Some(bytes) = snapshot {
+        let secrets: SecretsByRepo =
+            serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+        let mut store = SECRETS_STORE.lock().unwrap();
+        for (repo, values) in secrets {
+            store.entry(repo).or_default().extend(values);
+        }
+    }
+    let _ = SECRETS_VAULT.set(stronghold);
+    Ok(())
+}
+
+fn persist_secrets(store: &SecretsByRepo) -> Result<(), String> {
+    let Some(stronghold) = SECRETS_VAULT.get() else {
+        return Ok(());
+    };
+    let client = stronghold
+        .get_client(SECRETS_VAULT_CLIENT)
+        .map_err(|e| e.to_string())?;
+    let bytes = serde_json::to_vec(store).map_err(|e| e.to_string())?;
+    client
+        .store()
+        .insert(SECRETS_VAULT_RECORD.to_vec(), bytes, None)
+        .map_err(|e| e.to_string())?;
+    stronghold.save().map_err(|e| e.to_string())
+}
+
+pub fn check_app_secrets_satisfied(repo_path: &Path, app: &Application) -> bool {
     let secrets = get_app_secrets(app);
     let store = SECRETS_STORE.lock().unwrap();
+    let repo_secrets = store.get(&secrets_repo_key(repo_path));
     secrets
         .into_iter()
         .filter(|secret| secret.required)
-        .all(|secret| store.contains_key(&secret.name))
+        .all(|secret| {
+            repo_secrets
+                .map(|values| values.contains_key(&secret.name))
+                .unwrap_or(false)
+        })
 }
 
-pub fn set_secret(name: String, value: String) {
+pub fn set_secret(repo_path: &Path, name: String, value: String) -> Result<(), String> {
     let mut store = SECRETS_STORE.lock().unwrap();
-    store.insert(name, value);
+    store
+        .entry(secrets_repo_key(repo_path))
+        .or_default()
+        .insert(name, value);
+    persist_secrets(&store)
 }
 
-pub fn get_all_secrets() -> HashMap<String, String> {
+pub fn get_all_secrets(repo_path: &Path) -> HashMap<String, String> {
     let store = SECRETS_STORE.lock().unwrap();
-    store.clone()
+    store
+        .get(&secrets_repo_key(repo_path))
+        .cloned()
+        .unwrap_or_default()
 }
 
-pub fn clear_secrets() {
+fn known_secret_values() -> Vec<String> {
+    let store = SECRETS_STORE.lock().unwrap();
+    store
+        .values()
+        .flat_map(|secrets| secrets.values().cloned())
+        .collect()
+}
+
+/// Replaces every occurrence of a known secret value with `***`.
+pub fn redact_secrets(text: &str, secret_values: &[String]) -> String {
+    let mut values: Vec<&String> = secret_values
+        .iter()
+        .filter(|value| !value.is_empty())
+        .collect();
+    // Longest first so a secret containing another secret is fully masked.
+    values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+    let mut redacted = text.to_string();
+    for value in values {
+        if redacted.contains(value.as_str()) {
+            redacted = redacted.replace(value.as_str(), "***");
+        }
+    }
+    redacted
+}
+
+pub fn clear_secrets(repo_path: Option<&Path>) -> Result<(), String> {
     let mut store = SECRETS_STORE.lock().unwrap();
-    store.clear();
+    match repo_path {
+        Some(repo_path) => {
+            store.remove(&secrets_repo_key(repo_path));
+        }
+        None => store.clear(),
+    }
+    persist_secrets(&store)
 }
 
 // ============================================================================
 // Prerequisite Checks
 // ============================================================================
 
+/// Probe outcomes keyed by resolved command, so a tool shared by several
+/// prerequisites is only run once per backend.
+type PrerequisiteProbes = HashMap<String, (bool, Option<String>)>;
+
 fn check_prerequisites(
     app_root: &Path,
     prereq: &Prerequisite,
     ctx: &TemplateContext,
     env_map: &HashMap<String, String>,
     backend: &BackendContext,
+    probes: &mut PrerequisiteProbes,
 ) -> Result<PrerequisiteCheckResult> {
     let command = resolve_template(&prereq.command, ctx)?;
-    let (status, stdout, stderr) =
-        run_command_capture_backend(backend, &command, &app_root, env_map, None)
-            .context("Failed to run prerequisite command")?;
-
-    let mut installed = status.success();
-    let current_version = if installed {
-        parse_version(format!("{}\n{}", stdout, stderr).as_str())
-    } else {
-        None
+    let (succeeded, current_version) = match probes.get(&command) {
+        Some(probe) => probe.clone(),
+        None => {
+            let (status, stdout, stderr) =
+                run_command_capture_backend(backend, &command, app_root, env_map, None)
+                    .context("Failed to run prerequisite command")?;
+            let current_version = if status.success() {
+                parse_version(format!("{}\n{}", stdout, stderr).as_str())
+            } else {
+                None
+            };
+            let probe = (status.success(), current_version);
+            probes.insert(command.clone(), probe.clone());
+            probe
+        }
     };
 
+    let mut installed = succeeded;
+
     if let Some(required) = &prereq.version {
         let required_version =
             Version::parse(required).context("Invalid semver version in prerequisite")?;
@@ -559,17 +1304,54 @@ pub fn check_app_prerequisites(
     config: &FalckConfig,
     app: &Application,
     backend: &BackendContext,
+) -> Result<Vec<PrerequisiteCheckResult>> {
+    check_app_prerequisites_with(repo_path, config, app, backend, &mut HashMap::new())
+}
+
+fn check_app_prerequisites_with(
+    repo_path: &Path,
+    config: &FalckConfig,
+    app: &Application,
+    backend: &BackendContext,
+    probes: &mut PrerequisiteProbes,
 ) -> Result<Vec<PrerequisiteCheckResult>> {
     let mut results = Vec::new();
     let (app_root, ctx, env_map) = prepare_runtime_context(repo_path, config, app, backend)?;
     if let Some(prereqs) = &app.prerequisites {
         for prereq in prereqs {
-            results.push(check_prerequisites(&app_root, prereq, &ctx, &env_map, backend)?);
+            let result =
+                check_prerequisites(&app_root, prereq, &ctx, &env_map, backend, probes)?;
+            results.push(result);
         }
     }
     Ok(results)
 }
 
+/// Checks every application's prerequisites against a single resolved repo
+/// backend. Container apps are probed on the host, as in
+/// `resolve_backend_for_app`.
+pub fn check_all_app_prerequisites(
+    repo_path: &Path,
+    config: &FalckConfig,
+    repo_backend: &BackendContext,
+) -> Result<HashMap<String, Vec<PrerequisiteCheckResult>>> {
+    let host = BackendContext::host();
+    let mut host_probes = PrerequisiteProbes::new();
+    let mut repo_probes = PrerequisiteProbes::new();
+    let mut results = HashMap::new();
+    for app in &config.applications {
+        let (backend, probes) = if app.launch.container.is_some() {
+            (&host, &mut host_probes)
+        } else {
+            (repo_backend, &mut repo_probes)
+        };
+        let checks = check_app_prerequisites_with(repo_path, config, app, backend, probes)
+            .with_context(|| format!("Failed to check prerequisites for '{}'", app.id))?;
+        results.insert(app.id.clone(), checks);
+    }
+    Ok(results)
+}
+
 pub fn run_prerequisite_install(
     repo_path: &Path,
     config: &FalckConfig,
@@ -627,13 +1409,49 @@ fn parse_version(output: &str) -> Option<String> {
 // Setup / Launch / Cleanup
 // ============================================================================
 
+fn emit_setup_progress(
+    app_handle: Option<&AppHandle>,
+    repo_path: &Path,
+    app: &Application,
+    step: &SetupStep,
+    (index, total): (usize, usize),
+    status: &str,
+    stderr: Option<String>,
+) {
+    let Some(app_handle) = app_handle else {
+        return;
+    };
+    let _ = app_handle.emit(
+        "falck:setup-progress",
+        SetupProgressEvent {
+            repo_path: repo_path.to_string_lossy().to_string(),
+            app_id: app.id.clone(),
+            step_name: step.name.clone(),
+            index,
+            total,
+            status: status.to_string(),
+            stderr,
+        },
+    );
+}
+
+/// Delay before retry `attempt` (1-based): `base` doubled per attempt, capped
+/// at `SETUP_RETRY_MAX_DELAY`.
+fn setup_retry_delay(base: Duration, attempt: u32) -> Duration {
+    let factor = 1u32
+        .checked_shl(attempt.saturating_sub(1))
+        .unwrap_or(u32::MAX);
+    base.saturating_mul(factor).min(SETUP_RETRY_MAX_DELAY)
+}
+
 pub fn run_setup(
+    app_handle: Option<&AppHandle>,
     repo_path: &Path,
     config: &FalckConfig,
     app: &Application,
     backend: &BackendContext,
 ) -> Result<String> {
-    if !check_app_secrets_satisfied(app) {
+    if !check_app_secrets_satisfied(repo_path, app) {
         bail!("Required secrets not configured for this application");
     }
 
@@ -646,7 +1464,19 @@ pub fn run_setup(
 
     if let Some(setup) = &app.setup {
         if let Some(steps) = &setup.steps {
+            let total = steps.len();
             for (index, step) in steps.iter().enumerate() {
+                let progress = |status: &str, stderr: Option<String>| {
+                    emit_setup_progress(
+                        app_handle,
+                        repo_path,
+                        app,
+                        step,
+                        (index, total),
+                        status,
+                        stderr,
+                    )
+                };
                 if refresh_each_step && index > 0 {
                     let (_, refreshed_ctx, refreshed_env) =
                         prepare_runtime_context(repo_path, config, app, backend)?;
@@ -655,28 +1485,64 @@ pub fn run_setup(
                 }
                 if let Some(condition) = &step.only_if {
                     if !evaluate_condition(condition, &ctx)? {
+                        progress("skipped", None);
                         continue;
                     }
                 }
 
+                progress("running", None);
                 let command = resolve_template(&step.command, &ctx)?;
                 let timeout = step.timeout.unwrap_or(300);
                 let silent = step.silent.unwrap_or(false);
-                let status = run_command_backend(
-                    backend,
-                    &command,
-                    &app_root,
-                    &env_map,
-                    Some(timeout),
-                    silent,
-                )?;
+                let optional = step.optional.unwrap_or(false);
+                let retries = if optional { 0 } else { step.retries.unwrap_or(0) };
+                let retry_base = Duration::from_secs(step.retry_delay_secs.unwrap_or(5) as u64);
+                let mut attempt = 0;
+                let (status, stdout, stderr) = loop {
+                    let result = run_command_capture_backend(
+                        backend,
+                        &command,
+                        &app_root,
+                        &env_map,
+                        Some(timeout),
+                    );
+                    if !silent {
+                        if let Ok((_, stdout, stderr)) = &result {
+                            for line in stdout.lines().chain(stderr.lines()) {
+                                eprintln!("[falck][setup] {}: {}", step.name, line);
+                            }
+                        }
+                    }
+                    let succeeded = matches!(&result, Ok((status, _, _)) if status.success());
+                    if succeeded || attempt >= retries {
+                        match result {
+                            Ok(output) => break output,
+                            Err(err) => {
+                                progress("failed", Some(format!("{:#}", err)));
+                                return Err(err);
+                            }
+                        }
+                    }
+                    attempt += 1;
+                    eprintln!(
+                        "[falck][setup] step '{}' failed, retrying ({}/{})",
+                        step.name, attempt, retries
+                    );
+                    thread::sleep(setup_retry_delay(retry_base, attempt));
+                };
 
                 if !status.success() {
-                    if step.optional.unwrap_or(false) {
+                    progress("failed", Some(stderr.clone()));
+                    if optional {
                         continue;
                     }
-                    bail!("Setup step '{}' failed", step.name);
+                    let tail = output_tail(&stdout, &stderr, SETUP_OUTPUT_TAIL_LINES);
+                    if tail.is_empty() {
+                        bail!("Setup step '{}' failed", step.name);
+                    }
+                    bail!("Setup step '{}' failed:\n{}", step.name, tail);
                 }
+                progress("completed", None);
             }
         }
     }
@@ -684,6 +1550,73 @@ pub fn run_setup(
     Ok("Setup completed successfully".to_string())
 }
 
+/// Last `max_lines` lines of a command's stdout followed by its stderr.
+fn output_tail(stdout: &str, stderr: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = stdout
+        .lines()
+        .chain(stderr.lines())
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let start = lines.len().saturating_sub(max_lines);
+    lines[start..].join("\n")
+}
+
+/// Runs setup for every app, following `install_order` and then config
+/// order. Stops at the first app whose setup fails.
+pub fn run_all_setup(app_handle: &AppHandle, repo_path: &Path) -> Result<String, String> {
+    let config = load_config(repo_path).map_err(|e| e.to_string())?;
+    let app_ids: Vec<String> = config
+        .applications
+        .iter()
+        .map(|app| app.id.clone())
+        .collect();
+    let ordered = order_by_install_order(&config, &app_ids);
+    let total = ordered.len();
+    let emit = |app_id: &str, index: usize, status: &str, error: Option<String>| {
+        let _ = app_handle.emit(
+            "falck:setup-all-progress",
+            SetupAppProgressEvent {
+                repo_path: repo_path.to_string_lossy().to_string(),
+                app_id: app_id.to_string(),
+                index,
+                total,
+                status: status.to_string(),
+                error,
+            },
+        );
+    };
+
+    let mut completed = 0;
+    for (index, app_id) in ordered.iter().enumerate() {
+        let Some(app_config) = config.applications.iter().find(|app| &app.id == app_id) else {
+            continue;
+        };
+        let has_steps = app_config
+            .setup
+            .as_ref()
+            .and_then(|setup| setup.steps.as_ref())
+            .is_some_and(|steps| !steps.is_empty());
+        if !has_steps {
+            emit(app_id, index, "skipped", None);
+            continue;
+        }
+        emit(app_id, index, "running", None);
+        let result =
+            resolve_backend_for_app(app_handle, repo_path, app_config).and_then(|backend| {
+                run_setup(Some(app_handle), repo_path, &config, app_config, &backend)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(err) = result {
+            emit(app_id, index, "failed", Some(err.clone()));
+            return Err(format!("Setup failed for '{}': {}", app_id, err));
+        }
+        emit(app_id, index, "completed", None);
+        completed += 1;
+    }
+
+    Ok(format!("Setup completed for {} application(s)", completed))
+}
+
 pub fn check_setup_status(
     repo_path: &Path,
     config: &FalckConfig,
@@ -808,11 +1741,14 @@ pub fn check_setup_status(
                 message,
             })
         }
-        Err(err) => Ok(SetupCheckResult {
-            configured: true,
-            complete: false,
-            message: Some(err.to_string()),
-        }),
+        Err(err) => {
+            let secret_values: Vec<String> = get_all_secrets(repo_path).into_values().collect();
+            Ok(SetupCheckResult {
+                configured: true,
+                complete: false,
+                message: Some(redact_secrets(&err.to_string(), &secret_values)),
+            })
+        }
     }
 }
 
@@ -821,8 +1757,8 @@ pub fn launch_app(
     config: &FalckConfig,
     app: &Application,
     backend: &BackendContext,
-) -> Result<BackendProcess> {
-    if !check_app_secrets_satisfied(app) {
+) -> Result<LaunchedProcess> {
+    if !check_app_secrets_satisfied(repo_path, app) {
         bail!("Required secrets not configured for this application");
     }
 
@@ -834,49 +1770,313 @@ pub fn launch_app(
         .context("Launch command missing for this application")?;
     let command = resolve_template(command, &ctx)?;
 
-    if let Some(vm) = &backend.vm {
-        let exports = backend::vm_env_exports(&env_map);
-        let vm_root = backend::vm_app_root(vm, &app_root).map_err(|err| anyhow!(err))?;
-        let script = format!(
-            "{}cd {} && {}",
-            exports,
-            backend::shell_escape(&vm_root),
-            backend::background_launch_script(&command)
-        );
-        let cmd = backend::build_vm_command(vm, &script);
-        let (status, stdout, stderr) =
-            backend::spawn_capture_with_timeout(cmd, Some(20)).map_err(|err| anyhow!(err))?;
-        if !status.success() {
-            let combined = format!("{}\n{}", stdout.trim(), stderr.trim())
-                .trim()
-                .to_string();
-            let message = if combined.is_empty() {
-                "Failed to launch application inside VM.".to_string()
-            } else {
-                combined
+    if let Some(vm) = &backend.vm {
+        let exports = backend::vm_env_exports(&env_map);
+        let vm_root = backend::vm_app_root(vm, &app_root).map_err(|err| anyhow!(err))?;
+        let log_path = vm_log_path(&app.id);
+        let script = format!(
+            "{}cd {} && {}",
+            exports,
+            backend::shell_escape(&vm_root),
+            backend::background_launch_script(&command, Some(&log_path))
+        );
+        let cmd = backend::build_vm_command(vm, &script);
+        let (status, stdout, stderr) =
+            backend::spawn_capture_with_timeout(cmd, Some(20)).map_err(|err| anyhow!(err))?;
+        if !status.success() {
+            let combined = format!("{}\n{}", stdout.trim(), stderr.trim())
+                .trim()
+                .to_string();
+            let message = if combined.is_empty() {
+                "Failed to launch application inside VM.".to_string()
+            } else {
+                let secret_values: Vec<String> = get_all_secrets(repo_path).into_values().collect();
+                redact_secrets(&combined, &secret_values)
+            };
+            bail!(message);
+        }
+        let pid = backend::extract_pid(&format!("{}\n{}", stdout, stderr))
+            .map_err(|err| anyhow!(err))?;
+        Ok(LaunchedProcess {
+            process: BackendProcess::Virtualized {
+                pid,
+                vm: VmProcessHandle {
+                    provider: vm.provider,
+                    name: vm.name.clone(),
+                    limactl_path: vm.limactl_path.clone(),
+                },
+            },
+            output: Some(LaunchOutput::VmLog {
+                vm: vm.clone(),
+                path: log_path,
+            }),
+        })
+    } else {
+        let mut cmd = build_shell_command(&command);
+        cmd.current_dir(&app_root)
+            .envs(&env_map)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = cmd.spawn().context("Failed to spawn application process")?;
+        let output = match (child.stdout.take(), child.stderr.take()) {
+            (Some(stdout), Some(stderr)) => Some(LaunchOutput::Pipes { stdout, stderr }),
+            _ => None,
+        };
+        let pid = child.id();
+        // Reap the child so an exited process doesn't linger as a zombie that
+        // still answers the liveness check.
+        thread::spawn(move || {
+            let _ = child.wait();
+        });
+        Ok(LaunchedProcess {
+            process: BackendProcess::Host { pid },
+            output,
+        })
+    }
+}
+
+fn vm_log_path(app_id: &str) -> String {
+    let safe_id: String = app_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("/tmp/falck-{}.log", safe_id)
+}
+
+fn ready_probe_for(app: &Application) -> Option<ReadyProbe> {
+    let access = app.launch.access.as_ref()?;
+    if access.ready_signal.is_none() && access.port.is_none() {
+        return None;
+    }
+    let url = access
+        .url
+        .clone()
+        .or_else(|| access.port.map(|port| format!("http://localhost:{}", port)));
+    let timeout = app.launch.timeout.unwrap_or(DEFAULT_READY_TIMEOUT_SECS);
+    Some(ReadyProbe {
+        signal: access.ready_signal.clone(),
+        port: access.port,
+        url,
+        timeout: Duration::from_secs(timeout as u64),
+    })
+}
+
+/// Container output is not streamed through the app log, so a ready signal
+/// can't be matched: probe the published port, or wait for the container to
+/// be running when no port is declared.
+fn container_ready_probe(app: &Application) -> ReadyProbe {
+    let timeout = app.launch.timeout.unwrap_or(DEFAULT_READY_TIMEOUT_SECS);
+    let probe = ready_probe_for(app).unwrap_or(ReadyProbe {
+        signal: None,
+        port: None,
+        url: None,
+        timeout: Duration::from_secs(timeout as u64),
+    });
+    ReadyProbe {
+        signal: None,
+        ..probe
+    }
+}
+
+fn push_app_log(handle: u32, line: String) {
+    let mut logs = APP_LOGS.lock().unwrap_or_else(|err| err.into_inner());
+    let buffer = logs.entry(handle).or_default();
+    if buffer.len() >= APP_LOG_CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(line);
+}
+
+fn reset_app_logs(handle: u32) {
+    let mut logs = APP_LOGS.lock().unwrap_or_else(|err| err.into_inner());
+    logs.remove(&handle);
+}
+
+pub fn get_app_logs(handle: u32) -> Vec<String> {
+    let logs = APP_LOGS.lock().unwrap_or_else(|err| err.into_inner());
+    logs.get(&handle)
+        .map(|buffer| buffer.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+struct AppLogContext {
+    app: AppHandle,
+    repo_path: String,
+    app_id: String,
+    handle: u32,
+    signal: Option<String>,
+    seen: Arc<AtomicBool>,
+    secret_values: Vec<String>,
+}
+
+fn spawn_log_reader<R: Read + Send + 'static>(
+    reader: R,
+    stream: &'static str,
+    ctx: Arc<AppLogContext>,
+) {
+    thread::spawn(move || {
+        // Keep draining until EOF so the child never blocks on a full pipe.
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let raw = String::from_utf8_lossy(&buffer);
+                    let raw = raw.trim_end_matches(['\r', '\n']);
+                    if let Some(signal) = &ctx.signal {
+                        if raw.contains(signal.as_str()) {
+                            ctx.seen.store(true, Ordering::SeqCst);
+                        }
+                    }
+                    let line = redact_secrets(raw, &ctx.secret_values);
+                    push_app_log(ctx.handle, line.clone());
+                    let _ = ctx.app.emit(
+                        "falck:app-log",
+                        AppLogEvent {
+                            repo_path: ctx.repo_path.clone(),
+                            app_id: ctx.app_id.clone(),
+                            handle: ctx.handle,
+                            stream: stream.to_string(),
+                            line,
+                        },
+                    );
+                }
+            }
+        }
+    });
+}
+
+fn port_accepts_connections(port: u16) -> bool {
+    let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    std::net::TcpStream::connect_timeout(&addr, Duration::from_millis(300)).is_ok()
+}
+
+fn stream_app_output(ctx: Arc<AppLogContext>, process: &BackendProcess, output: LaunchOutput) {
+    match output {
+        LaunchOutput::Pipes { stdout, stderr } => {
+            spawn_log_reader(stdout, "stdout", ctx.clone());
+            spawn_log_reader(stderr, "stderr", ctx);
+        }
+        LaunchOutput::VmLog { vm, path } => {
+            let BackendProcess::Virtualized { pid, .. } = process else {
+                return;
             };
-            bail!(message);
+            // The backgrounded process writes both streams to one file; follow it
+            // until the process exits.
+            let script = format!(
+                "tail -n +1 -F --pid={} {} 2>/dev/null",
+                pid,
+                backend::shell_escape(&path)
+            );
+            let mut cmd = backend::build_vm_command(&vm, &script);
+            cmd.stdout(Stdio::piped()).stderr(Stdio::null());
+            match cmd.spawn() {
+                Ok(mut child) => {
+                    if let Some(stdout) = child.stdout.take() {
+                        spawn_log_reader(stdout, "stdout", ctx);
+                    }
+                    thread::spawn(move || {
+                        let _ = child.wait();
+                    });
+                }
+                Err(err) => {
+                    eprintln!(
+                        "[falck][launch] failed to follow VM logs for {}: {}",
+                        ctx.app_id, err
+                    );
+                }
+            }
         }
-        let pid = backend::extract_pid(&format!("{}\n{}", stdout, stderr))
-            .map_err(|err| anyhow!(err))?;
-        Ok(BackendProcess::Virtualized {
-            pid,
-            vm: VmProcessHandle {
-                provider: vm.provider,
-                name: vm.name.clone(),
-                limactl_path: vm.limactl_path.clone(),
-            },
-        })
-    } else {
-        let mut cmd = build_shell_command(&command);
-        cmd.current_dir(&app_root)
-            .envs(&env_map)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
+    }
+}
+
+fn is_app_registered(app: &AppHandle, handle: u32) -> bool {
+    let state = app.state::<FalckProcessState>();
+    let guard = match state.0.lock() {
+        Ok(guard) => guard,
+        Err(err) => err.into_inner(),
+    };
+    guard.contains_key(&handle)
+}
 
-        let child = cmd.spawn().context("Failed to spawn application process")?;
-        Ok(BackendProcess::Host { pid: child.id() })
+fn watch_app_ready(
+    app: AppHandle,
+    repo_path: String,
+    app_id: String,
+    process: &BackendProcess,
+    probe: Option<ReadyProbe>,
+    output: Option<LaunchOutput>,
+) {
+    let handle = backend_process_pid(process);
+    reset_app_logs(handle);
+    let seen = Arc::new(AtomicBool::new(false));
+    if let Some(output) = output {
+        let ctx = Arc::new(AppLogContext {
+            app: app.clone(),
+            repo_path: repo_path.clone(),
+            app_id: app_id.clone(),
+            handle,
+            signal: probe.as_ref().and_then(|probe| probe.signal.clone()),
+            seen: seen.clone(),
+            secret_values: get_all_secrets(Path::new(&repo_path))
+                .into_values()
+                .collect(),
+        });
+        stream_app_output(ctx, process, output);
     }
+    let Some(probe) = probe else {
+        return;
+    };
+
+    let process = process.clone();
+    thread::spawn(move || {
+        let deadline = Instant::now() + probe.timeout;
+        let ready = loop {
+            if !is_app_registered(&app, handle) {
+                return;
+            }
+            let ready = match (&probe.signal, probe.port, &process) {
+                (Some(_), _, _) => seen.load(Ordering::SeqCst),
+                (None, Some(port), _) => port_accepts_connections(port),
+                (None, None, BackendProcess::Container { handle, limactl }) => {
+                    crate::containers::container_running(limactl, handle)
+                }
+                (None, None, _) => false,
+            };
+            if ready {
+                break true;
+            }
+            if Instant::now() >= deadline {
+                eprintln!(
+                    "[falck][launch] {} did not become ready within {}s",
+                    app_id,
+                    probe.timeout.as_secs()
+                );
+                break false;
+            }
+            thread::sleep(READY_POLL_INTERVAL);
+        };
+
+        let _ = app.emit(
+            "falck:app-ready",
+            AppReadyEvent {
+                repo_path,
+                app_id,
+                handle,
+                url: probe.url,
+                ready,
+            },
+        );
+    });
 }
 
 pub fn run_cleanup(
@@ -962,7 +2162,7 @@ fn resolve_runtime_paths(
 ) -> Result<(PathBuf, PathBuf)> {
     match backend.mode {
         backend::BackendMode::Host => Ok((repo_path.to_path_buf(), app_root.to_path_buf())),
-        backend::BackendMode::Virtualized => {
+        backend::BackendMode::Virtualized | backend::BackendMode::Container => {
             let vm = backend
                 .vm
                 .as_ref()
@@ -984,17 +2184,54 @@ fn prepare_runtime_context(
     let base_env = load_backend_env(backend);
     let (ctx_repo_root, ctx_app_root) = resolve_runtime_paths(repo_path, &app_root, backend)?;
     let ctx = TemplateContext::new_for_backend(&ctx_repo_root, &ctx_app_root, &base_env, backend);
-    let env_map = build_env_map(config, app, &ctx, &base_env)?;
+    let env_map = build_env_map(repo_path, config, app, &ctx, &base_env)?;
     Ok((app_root, ctx, env_map))
 }
 
+fn load_env_file(repo_path: &Path, config: &FalckConfig) -> Result<HashMap<String, String>> {
+    let configured = config
+        .repository
+        .as_ref()
+        .and_then(|repo| repo.env_file.as_deref())
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty());
+    let env_path = match configured {
+        Some(file) => {
+            let path = repo_path.join(normalize_relative_path(file)?);
+            if !path.is_file() {
+                bail!("Configured env_file not found: {}", file);
+            }
+            path
+        }
+        None => {
+            let path = repo_path.join(".env");
+            if !path.is_file() {
+                return Ok(HashMap::new());
+            }
+            path
+        }
+    };
+
+    let mut env_map = HashMap::new();
+    let entries = dotenvy::from_path_iter(&env_path)
+        .with_context(|| format!("Failed to read {:?}", env_path))?;
+    for entry in entries {
+        let (key, value) = entry.with_context(|| format!("Failed to parse {:?}", env_path))?;
+        env_map.insert(key, value);
+    }
+    Ok(env_map)
+}
+
 fn build_env_map(
+    repo_path: &Path,
     config: &FalckConfig,
     app: &Application,
     ctx: &TemplateContext,
     base_env: &HashMap<String, String>,
 ) -> Result<HashMap<String, String>> {
-    let mut env_map: HashMap<String, String> = base_env.clone();
+    // `.env` values are defaults: everything Falck or the config sets wins.
+    let mut env_map: HashMap<String, String> = load_env_file(repo_path, config)?;
+    env_map.extend(base_env.clone());
 
     if let Some(global_env) = &config.global_env {
         for (key, value) in global_env {
@@ -1008,16 +2245,17 @@ fn build_env_map(
         }
     }
 
-    env_map.extend(get_all_secrets());
+    env_map.extend(get_all_secrets(repo_path));
     Ok(env_map)
 }
 
 fn build_container_env_map(
+    repo_path: &Path,
     config: &FalckConfig,
     app: &Application,
     ctx: &TemplateContext,
 ) -> Result<HashMap<String, String>> {
-    let mut env_map: HashMap<String, String> = HashMap::new();
+    let mut env_map: HashMap<String, String> = load_env_file(repo_path, config)?;
 
     if let Some(global_env) = &config.global_env {
         for (key, value) in global_env {
@@ -1031,7 +2269,7 @@ fn build_container_env_map(
         }
     }
 
-    env_map.extend(get_all_secrets());
+    env_map.extend(get_all_secrets(repo_path));
     Ok(env_map)
 }
 
@@ -1081,6 +2319,49 @@ fn resolve_path_from_app_root(input: &str, app_root: &Path) -> PathBuf {
     }
 }
 
+/// Resolves `.` and `..` without touching the filesystem, so bind sources
+/// that do not exist yet can still be checked.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// Resolves symlinks in the deepest existing ancestor of `path` and appends
+/// the rest lexically, so a path that doesn't exist yet can't escape through a
+/// symlinked parent directory.
+fn canonicalize_existing_prefix(path: &Path) -> PathBuf {
+    let components: Vec<Component> = path.components().collect();
+    for split in (1..=components.len()).rev() {
+        let prefix: PathBuf = components[..split].iter().collect();
+        if let Ok(canonical) = std::fs::canonicalize(&prefix) {
+            let rest: PathBuf = components[split..].iter().collect();
+            return normalize_lexically(&canonical.join(rest));
+        }
+    }
+    normalize_lexically(path)
+}
+
+fn ensure_bind_source_in_repo(source: &Path, repo_path: &Path) -> Result<()> {
+    let repo_root =
+        std::fs::canonicalize(repo_path).unwrap_or_else(|_| normalize_lexically(repo_path));
+    if !canonicalize_existing_prefix(source).starts_with(&repo_root) {
+        bail!(
+            "Container mount source '{}' must be inside the repository",
+            source.display()
+        );
+    }
+    Ok(())
+}
+
 fn build_container_launch_spec(
     repo_path: &Path,
     config: &FalckConfig,
@@ -1143,6 +2424,7 @@ fn build_container_launch_spec(
                         let source_template = resolve_template(source, &ctx)?;
                         let source_path =
                             resolve_path_from_app_root(&source_template, &app_root);
+                        ensure_bind_source_in_repo(&source_path, repo_path)?;
                         Ok(crate::containers::ContainerMountSpec {
                             source: crate::containers::ContainerMountSource::Bind(source_path),
                             target: mount.target.clone(),
@@ -1171,7 +2453,7 @@ fn build_container_launch_spec(
         }]
     };
 
-    let env = build_container_env_map(config, app, &ctx)?;
+    let env = build_container_env_map(repo_path, config, app, &ctx)?;
 
     Ok(crate::containers::ContainerLaunchSpec {
         repo_path: repo_path.to_path_buf(),
@@ -1233,7 +2515,12 @@ fn run_command_capture_backend(
         let cmd = backend::build_vm_command(vm, &script);
         let (status, stdout, stderr) = backend::spawn_capture_with_timeout(cmd, timeout_secs)
             .map_err(|err| anyhow!(err))?;
-        Ok((status, stdout, stderr))
+        let secret_values = known_secret_values();
+        Ok((
+            status,
+            redact_secrets(&stdout, &secret_values),
+            redact_secrets(&stderr, &secret_values),
+        ))
     } else {
         run_command_capture(command, cwd, env_map, timeout_secs)
     }
@@ -1290,7 +2577,7 @@ fn run_command(
                 return Ok(status);
             }
             if start.elapsed() > timeout_duration {
-                let _ = child.kill();
+                backend::kill_and_reap(&mut child);
                 bail!("Command timed out after {} seconds", timeout);
             }
             std::thread::sleep(Duration::from_millis(200));
@@ -1357,8 +2644,9 @@ fn run_command_capture(
     let stdout_bytes = stdout_handle.join().unwrap_or_default();
     let stderr_bytes = stderr_handle.join().unwrap_or_default();
 
-    let stdout = String::from_utf8_lossy(&stdout_bytes).to_string();
-    let stderr = String::from_utf8_lossy(&stderr_bytes).to_string();
+    let secret_values = known_secret_values();
+    let stdout = redact_secrets(&String::from_utf8_lossy(&stdout_bytes), &secret_values);
+    let stderr = redact_secrets(&String::from_utf8_lossy(&stderr_bytes), &secret_values);
 
     Ok((status, stdout, stderr))
 }
@@ -1388,7 +2676,7 @@ pub fn kill_app(pid: u32) -> Result<()> {
     Ok(())
 }
 
-fn build_shell_command(command: &str) -> Command {
+pub(crate) fn build_shell_command(command: &str) -> Command {
     if cfg!(target_os = "windows") {
         let mut cmd = Command::new("cmd");
         cmd.arg("/C").arg(command);
@@ -1573,7 +2861,9 @@ impl TemplateContext {
         backend: Option<&BackendContext>,
     ) -> Self {
         let os = match backend.map(|value| value.mode) {
-            Some(backend::BackendMode::Virtualized) => "linux".to_string(),
+            Some(backend::BackendMode::Virtualized | backend::BackendMode::Container) => {
+                "linux".to_string()
+            }
             _ => env::consts::OS.to_string(),
         };
         let arch = match env::consts::ARCH {
@@ -1597,7 +2887,10 @@ impl TemplateContext {
             .or_else(|| env::var("SHELL").or_else(|_| env::var("ComSpec")).ok())
             .unwrap_or_default();
         if system_shell.is_empty()
-            && matches!(backend.map(|value| value.mode), Some(backend::BackendMode::Virtualized))
+            && matches!(
+                backend.map(|value| value.mode),
+                Some(backend::BackendMode::Virtualized | backend::BackendMode::Container)
+            )
         {
             system_shell = "/bin/sh".to_string();
         }
@@ -1622,7 +2915,9 @@ impl TemplateContext {
             "system.user" => Ok(self.system_user.clone()),
             "system.shell" => Ok(self.system_shell.clone()),
             _ => {
-                if let Some(rest) = key.strip_prefix("env.") {
+                if let Some(value) = dynamic_template_value(key) {
+                    value
+                } else if let Some(rest) = key.strip_prefix("env.") {
                     Ok(self
                         .env
                         .get(rest)
@@ -1636,16 +2931,64 @@ impl TemplateContext {
     }
 }
 
+fn dynamic_template_value(key: &str) -> Option<Result<String>> {
+    match key {
+        "now.timestamp" => Some(unix_now().map(|secs| secs.to_string())),
+        "now.date" => Some(unix_now().map(format_unix_date)),
+        "random.hex" => Some(random_hex(4)),
+        _ => None,
+    }
+}
+
+fn unix_now() -> Result<u64> {
+    let elapsed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .context("System clock is before the Unix epoch")?;
+    Ok(elapsed.as_secs())
+}
+
+fn format_unix_date(secs: u64) -> String {
+    // Civil-from-days conversion (proleptic Gregorian calendar, UTC).
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn random_hex(bytes: usize) -> Result<String> {
+    let mut buffer = vec![0u8; bytes];
+    getrandom::getrandom(&mut buffer)
+        .map_err(|err| anyhow!("Failed to generate random value: {}", err))?;
+    Ok(buffer.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
 fn resolve_template(input: &str, ctx: &TemplateContext) -> Result<String> {
     let re = Regex::new(r"\{\{\s*([^}]+)\s*\}\}")?;
     let mut result = String::new();
     let mut last_index = 0;
+    // Dynamic values are computed once so repeated references expand identically.
+    let mut dynamic_values: HashMap<String, String> = HashMap::new();
 
     for cap in re.captures_iter(input) {
         let m = cap.get(0).unwrap();
         result.push_str(&input[last_index..m.start()]);
         let key = cap.get(1).unwrap().as_str().trim();
-        let replacement = ctx.resolve(key)?;
+        let replacement = if let Some(value) = dynamic_values.get(key) {
+            value.clone()
+        } else if let Some(value) = dynamic_template_value(key) {
+            let value = value?;
+            dynamic_values.insert(key.to_string(), value.clone());
+            value
+        } else {
+            ctx.resolve(key)?
+        };
         result.push_str(&replacement);
         last_index = m.end();
     }
@@ -1673,6 +3016,9 @@ enum Token {
     Or,
     Not,
     Contains,
+    StartsWith,
+    EndsWith,
+    Matches,
     LParen,
     RParen,
 }
@@ -1766,15 +3112,23 @@ fn tokenize(input: &str) -> Result<Vec<Token>> {
             i += 2;
             continue;
         }
-        if input[i..].starts_with("contains") {
-            let next = i + "contains".len();
-            if next >= bytes.len()
-                || !((bytes[next] as char).is_ascii_alphanumeric() || bytes[next] == b'_')
-            {
-                tokens.push(Token::Contains);
-                i += "contains".len();
-                continue;
-            }
+        let keyword = [
+            ("contains", Token::Contains),
+            ("startswith", Token::StartsWith),
+            ("endswith", Token::EndsWith),
+            ("matches", Token::Matches),
+        ]
+        .into_iter()
+        .find(|(word, _)| {
+            let next = i + word.len();
+            input[i..].starts_with(word)
+                && (next >= bytes.len()
+                    || !((bytes[next] as char).is_ascii_alphanumeric() || bytes[next] == b'_'))
+        });
+        if let Some((word, token)) = keyword {
+            tokens.push(token);
+            i += word.len();
+            continue;
         }
         match c {
             '(' => {
@@ -1909,7 +3263,10 @@ impl<'a> ConditionParser<'a> {
             | Some(Token::Lt)
             | Some(Token::Ge)
             | Some(Token::Le)
-            | Some(Token::Contains) => {
+            | Some(Token::Contains)
+            | Some(Token::StartsWith)
+            | Some(Token::EndsWith)
+            | Some(Token::Matches) => {
                 let op_token = self.next().unwrap();
                 let right = self.parse_primary()?;
                 self.compare_values(left, op_token, right)
@@ -1937,6 +3294,14 @@ impl<'a> ConditionParser<'a> {
             Token::Eq => Ok(left.as_string() == right.as_string()),
             Token::Ne => Ok(left.as_string() != right.as_string()),
             Token::Contains => Ok(left.as_string().contains(&right.as_string())),
+            Token::StartsWith => Ok(left.as_string().starts_with(&right.as_string())),
+            Token::EndsWith => Ok(left.as_string().ends_with(&right.as_string())),
+            Token::Matches => {
+                let pattern = right.as_string();
+                let re = Regex::new(&pattern)
+                    .with_context(|| format!("Invalid regex in condition: {}", pattern))?;
+                Ok(re.is_match(&left.as_string()))
+            }
             Token::Gt | Token::Lt | Token::Ge | Token::Le => {
                 let left_num = left.as_number().context("Left side is not numeric")?;
                 let right_num = right.as_number().context("Right side is not numeric")?;
@@ -2014,6 +3379,57 @@ pub async fn load_falck_config(repo_path: String) -> Result<FalckConfig, String>
     .await
 }
 
+#[tauri::command]
+pub async fn validate_falck_config(repo_path: String) -> Result<Vec<ValidationIssue>, String> {
+    run_blocking_value(move || validate_config(Path::new(&repo_path))).await
+}
+
+#[tauri::command]
+pub async fn scaffold_falck_config(
+    repo_path: String,
+    app_name: String,
+    app_type: String,
+    force: Option<bool>,
+) -> Result<String, String> {
+    run_blocking(move || {
+        let path = Path::new(&repo_path);
+        scaffold_config(path, &app_name, &app_type, force.unwrap_or(false))
+            .map(|config_path| config_path.to_string_lossy().to_string())
+            .map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// Removes VM port forwards for ports no longer declared by any app in the
+/// repo config. Returns the ports that were removed.
+#[tauri::command]
+pub async fn prune_vm_port_forwards(app: AppHandle, repo_path: String) -> Result<Vec<u16>, String> {
+    run_blocking(move || {
+        let path = Path::new(&repo_path);
+        let config = load_config(path).map_err(|e| e.to_string())?;
+        let backend_ctx = backend::resolve_backend(&app, path)?;
+        let Some(vm) = &backend_ctx.vm else {
+            return Ok(Vec::new());
+        };
+        let declared: HashSet<u16> = config
+            .applications
+            .iter()
+            .filter(|app_config| app_config.launch.container.is_none())
+            .flat_map(collect_app_ports)
+            .collect();
+        let stale: Vec<u16> = backend::vm_forwarded_ports(vm)
+            .into_iter()
+            .filter(|port| !declared.contains(port))
+            .collect();
+        if stale.is_empty() {
+            return Ok(stale);
+        }
+        backend::remove_vm_port_forwards(Some(&app), vm, &stale)?;
+        Ok(stale)
+    })
+    .await
+}
+
 #[tauri::command]
 pub async fn check_falck_prerequisites(
     app: AppHandle,
@@ -2035,6 +3451,28 @@ pub async fn check_falck_prerequisites(
     .await
 }
 
+#[tauri::command]
+pub async fn check_all_prerequisites(
+    app: AppHandle,
+    repo_path: String,
+) -> Result<HashMap<String, Vec<PrerequisiteCheckResult>>, String> {
+    run_blocking(move || {
+        let path = Path::new(&repo_path);
+        let config = load_config(path).map_err(|e| e.to_string())?;
+        let needs_repo_backend = config
+            .applications
+            .iter()
+            .any(|app_config| app_config.launch.container.is_none());
+        let backend = if needs_repo_backend {
+            backend::resolve_backend(&app, path)?
+        } else {
+            BackendContext::host()
+        };
+        check_all_app_prerequisites(path, &config, &backend).map_err(|e| e.to_string())
+    })
+    .await
+}
+
 #[tauri::command]
 pub async fn run_falck_prerequisite_install(
     app: AppHandle,
@@ -2086,9 +3524,8 @@ pub async fn get_app_secrets_for_config(
 }
 
 #[tauri::command]
-pub async fn set_app_secret(name: String, value: String) -> Result<(), String> {
-    set_secret(name, value);
-    Ok(())
+pub async fn set_app_secret(repo_path: String, name: String, value: String) -> Result<(), String> {
+    run_blocking(move || set_secret(Path::new(&repo_path), name, value)).await
 }
 
 #[tauri::command]
@@ -2102,7 +3539,7 @@ pub async fn check_secrets_satisfied(repo_path: String, app_id: String) -> Resul
             .find(|app| app.id == app_id)
             .ok_or_else(|| "Application not found".to_string())?;
 
-        Ok(check_app_secrets_satisfied(app))
+        Ok(check_app_secrets_satisfied(path, app))
     })
     .await
 }
@@ -2144,27 +3581,33 @@ pub async fn run_falck_setup(
             .ok_or_else(|| "Application not found".to_string())?;
         let backend = resolve_backend_for_app(&app, path, app_config)?;
 
-        run_setup(path, &config, app_config, &backend).map_err(|e| e.to_string())
+        run_setup(Some(&app), path, &config, app_config, &backend).map_err(|e| e.to_string())
     })
     .await
 }
 
 #[tauri::command]
-pub async fn launch_falck_app(
-    app: AppHandle,
-    state: State<'_, FalckProcessState>,
+pub async fn run_all_falck_setup(app: AppHandle, repo_path: String) -> Result<String, String> {
+    run_blocking(move || run_all_setup(&app, Path::new(&repo_path))).await
+}
+
+enum LaunchOutcome {
+    Process(LaunchedProcess, Option<ReadyProbe>),
+    Container(crate::containers::ContainerHandle, PathBuf, ReadyProbe),
+}
+
+async fn launch_app_by_id(
+    app: &AppHandle,
+    state: &FalckProcessState,
     repo_path: String,
     app_id: String,
 ) -> Result<LaunchResult, String> {
-    #[derive(Debug)]
-    enum LaunchOutcome {
-        Process(BackendProcess),
-        Container(crate::containers::ContainerHandle),
-    }
-
     let app_handle = app.clone();
+    let event_repo_path = repo_path.clone();
+    let event_app_id = app_id.clone();
     let outcome = run_blocking(move || {
         let path = Path::new(&repo_path);
+        let _cancel = backend::cancel_scope(path);
         let config = load_config(path).map_err(|e| e.to_string())?;
         let app_config = config
             .applications
@@ -2176,7 +3619,13 @@ pub async fn launch_falck_app(
                 build_container_launch_spec(path, &config, app_config).map_err(|e| e.to_string())?;
             let handle = crate::containers::launch_container(&app_handle, spec)
                 .map_err(|e| e.to_string())?;
-            Ok(LaunchOutcome::Container(handle))
+            let limactl = crate::containers::limactl_path(&app_handle)
+                .ok_or_else(|| "Lima is unavailable.".to_string())?;
+            Ok(LaunchOutcome::Container(
+                handle,
+                limactl,
+                container_ready_probe(app_config),
+            ))
         } else {
             let backend_ctx = resolve_backend_for_app(&app_handle, path, app_config)?;
             if let Some(vm) = &backend_ctx.vm {
@@ -2184,29 +3633,218 @@ pub async fn launch_falck_app(
                 backend::ensure_vm_port_forwards(Some(&app_handle), vm, &ports)
                     .map_err(|e| e.to_string())?;
             }
-            let process = launch_app(path, &config, app_config, &backend_ctx)
+            let launched = launch_app(path, &config, app_config, &backend_ctx)
                 .map_err(|e| e.to_string())?;
-            Ok(LaunchOutcome::Process(process))
+            Ok(LaunchOutcome::Process(launched, ready_probe_for(app_config)))
         }
     })
     .await?;
 
     match outcome {
-        LaunchOutcome::Process(process) => {
-            let pid = backend_process_pid(&process);
-            register_running_app(&state, RunningFalckApp { process });
+        LaunchOutcome::Process(launched, probe) => {
+            let pid = backend_process_pid(&launched.process);
+            register_running_app(
+                state,
+                RunningFalckApp {
+                    process: launched.process.clone(),
+                    repo_path: PathBuf::from(&event_repo_path),
+                    app_id: event_app_id.clone(),
+                },
+            );
+            watch_app_ready(
+                app.clone(),
+                event_repo_path,
+                event_app_id,
+                &launched.process,
+                probe,
+                launched.output,
+            );
             Ok(LaunchResult {
                 kind: "process".to_string(),
                 pid: Some(pid),
                 container: None,
             })
         }
-        LaunchOutcome::Container(handle) => Ok(LaunchResult {
-            kind: "container".to_string(),
-            pid: None,
-            container: Some(handle),
-        }),
+        LaunchOutcome::Container(handle, limactl, probe) => {
+            let process = BackendProcess::Container {
+                handle: handle.clone(),
+                limactl,
+            };
+            register_running_app(
+                state,
+                RunningFalckApp {
+                    process: process.clone(),
+                    repo_path: PathBuf::from(&event_repo_path),
+                    app_id: event_app_id.clone(),
+                },
+            );
+            watch_app_ready(
+                app.clone(),
+                event_repo_path,
+                event_app_id,
+                &process,
+                Some(probe),
+                None,
+            );
+            Ok(LaunchResult {
+                kind: "container".to_string(),
+                pid: None,
+                container: Some(handle),
+            })
+        }
+    }
+}
+
+async fn stop_launched_app(app: &AppHandle, state: &FalckProcessState, result: LaunchResult) {
+    if let Some(pid) = result.pid {
+        if let Some(running) = unregister_running_app(state, pid) {
+            let _ = run_blocking(move || {
+                kill_backend_process(running.process).map_err(|e| e.to_string())
+            })
+            .await;
+        }
+    }
+    if let Some(container) = result.container {
+        unregister_running_app(state, container_app_handle(&container.id));
+        let _ = crate::containers::stop_container(
+            app.clone(),
+            container.id,
+            container.vm,
+            container.name,
+        )
+        .await;
+    }
+}
+
+fn order_by_launch_order(config: &FalckConfig, app_ids: &[String]) -> Vec<String> {
+    order_app_ids(config.launch_order.as_deref(), app_ids)
+}
+
+fn order_by_install_order(config: &FalckConfig, app_ids: &[String]) -> Vec<String> {
+    order_app_ids(config.install_order.as_deref(), app_ids)
+}
+
+fn order_app_ids(order: Option<&[String]>, app_ids: &[String]) -> Vec<String> {
+    let mut ordered: Vec<String> = Vec::new();
+    if let Some(order) = order {
+        for id in order {
+            if app_ids.contains(id) && !ordered.contains(id) {
+                ordered.push(id.clone());
+            }
+        }
+    }
+    for id in app_ids {
+        if !ordered.contains(id) {
+            ordered.push(id.clone());
+        }
+    }
+    ordered
+}
+
+async fn launch_apps_in_order(
+    app: &AppHandle,
+    state: &FalckProcessState,
+    repo_path: String,
+    app_ids: Vec<String>,
+) -> Result<HashMap<String, LaunchResult>, String> {
+    let mut launched: Vec<(String, LaunchResult)> = Vec::new();
+    for app_id in app_ids {
+        match launch_app_by_id(app, state, repo_path.clone(), app_id.clone()).await {
+            Ok(result) => launched.push((app_id, result)),
+            Err(err) => {
+                for (_, result) in launched.into_iter().rev() {
+                    stop_launched_app(app, state, result).await;
+                }
+                return Err(format!("Failed to launch '{}': {}", app_id, err));
+            }
+        }
+    }
+
+    Ok(launched.into_iter().collect())
+}
+
+async fn load_config_async(repo_path: &str) -> Result<FalckConfig, String> {
+    let repo_path = repo_path.to_string();
+    run_blocking(move || load_config(Path::new(&repo_path)).map_err(|e| e.to_string())).await
+}
+
+pub async fn launch_all_apps(
+    app: &AppHandle,
+    state: &FalckProcessState,
+    repo_path: String,
+) -> Result<HashMap<String, LaunchResult>, String> {
+    let config = load_config_async(&repo_path).await?;
+    let app_ids: Vec<String> = config.applications.iter().map(|app| app.id.clone()).collect();
+    let ordered = order_by_launch_order(&config, &app_ids);
+    launch_apps_in_order(app, state, repo_path, ordered).await
+}
+
+pub async fn launch_group(
+    app: &AppHandle,
+    state: &FalckProcessState,
+    repo_path: String,
+    group_name: String,
+) -> Result<HashMap<String, LaunchResult>, String> {
+    let config = load_config_async(&repo_path).await?;
+    let group = config
+        .groups
+        .as_ref()
+        .and_then(|groups| groups.iter().find(|group| group.name == group_name))
+        .ok_or_else(|| format!("Group '{}' not found", group_name))?;
+    for app_id in &group.apps {
+        if !config.applications.iter().any(|app| &app.id == app_id) {
+            return Err(format!(
+                "Group '{}' references unknown application '{}'",
+                group_name, app_id
+            ));
+        }
     }
+    let ordered = order_by_launch_order(&config, &group.apps);
+    launch_apps_in_order(app, state, repo_path, ordered).await
+}
+
+#[tauri::command]
+pub async fn launch_falck_app(
+    app: AppHandle,
+    state: State<'_, FalckProcessState>,
+    repo_path: String,
+    app_id: String,
+) -> Result<LaunchResult, String> {
+    launch_app_by_id(&app, &state, repo_path, app_id).await
+}
+
+#[tauri::command]
+pub async fn restart_falck_app(
+    app: AppHandle,
+    state: State<'_, FalckProcessState>,
+    handle: u32,
+) -> Result<LaunchResult, String> {
+    let running = unregister_running_app(&state, handle)
+        .ok_or_else(|| format!("No running application with handle {}", handle))?;
+    let repo_path = running.repo_path.to_string_lossy().to_string();
+    let app_id = running.app_id.clone();
+    run_blocking(move || kill_backend_process(running.process).map_err(|e| e.to_string()))
+        .await?;
+    launch_app_by_id(&app, &state, repo_path, app_id).await
+}
+
+#[tauri::command]
+pub async fn launch_all_falck_apps(
+    app: AppHandle,
+    state: State<'_, FalckProcessState>,
+    repo_path: String,
+) -> Result<HashMap<String, LaunchResult>, String> {
+    launch_all_apps(&app, &state, repo_path).await
+}
+
+#[tauri::command]
+pub async fn launch_falck_group(
+    app: AppHandle,
+    state: State<'_, FalckProcessState>,
+    repo_path: String,
+    group_name: String,
+) -> Result<HashMap<String, LaunchResult>, String> {
+    launch_group(&app, &state, repo_path, group_name).await
 }
 
 #[tauri::command]
@@ -2264,6 +3902,19 @@ pub async fn kill_falck_app(state: State<'_, FalckProcessState>, pid: u32) -> Re
     .await
 }
 
+/// Apps launched through Falck, including Dockerfile-based containers.
+#[tauri::command]
+pub async fn list_running_apps(
+    state: State<'_, FalckProcessState>,
+) -> Result<Vec<RunningAppInfo>, String> {
+    Ok(list_running(&state))
+}
+
+#[tauri::command]
+pub async fn get_falck_app_logs(handle: u32) -> Result<Vec<String>, String> {
+    Ok(get_app_logs(handle))
+}
+
 #[tauri::command]
 pub async fn check_port_available(port: u16) -> bool {
     run_blocking_value(move || is_port_available(port))
@@ -2277,7 +3928,102 @@ pub async fn open_browser_to_url(url: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub async fn clear_all_secrets() -> Result<(), String> {
-    clear_secrets();
-    Ok(())
+pub async fn clear_all_secrets(repo_path: Option<String>) -> Result<(), String> {
+    run_blocking(move || clear_secrets(repo_path.as_deref().map(Path::new))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::atomic::AtomicUsize;
+
+    static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    struct TempDir {
+        dir: PathBuf,
+    }
+
+    impl TempDir {
+        fn new() -> Self {
+            let dir = env::temp_dir().join(format!(
+                "falck-config-test-{}-{}",
+                std::process::id(),
+                TEMP_COUNTER.fetch_add(1, Ordering::SeqCst)
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self { dir }
+        }
+
+        fn write(&self, file: &str, contents: &str) {
+            fs::write(self.dir.join(file), contents).unwrap();
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn test_config(yaml: &str) -> FalckConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn build_env_map_gives_dotenv_lowest_precedence() {
+        let repo = TempDir::new();
+        repo.write(".env", "PORT=1111\nSHELL_VAR=dotenv\nONLY_DOTENV=yes\n");
+        let config = test_config(
+            r#"
+version: "1.0"
+global_env:
+  PORT: "2222"
+applications:
+  - id: web
+    name: Web
+    type: web
+    root: "."
+    launch:
+      command: "npm run dev"
+"#,
+        );
+        let app = &config.applications[0];
+        let ctx = TemplateContext::new(&repo.dir, &repo.dir);
+        let base_env = HashMap::from([("SHELL_VAR".to_string(), "shell".to_string())]);
+
+        let env_map = build_env_map(&repo.dir, &config, app, &ctx, &base_env).unwrap();
+
+        assert_eq!(env_map.get("PORT").map(String::as_str), Some("2222"));
+        assert_eq!(env_map.get("SHELL_VAR").map(String::as_str), Some("shell"));
+        assert_eq!(env_map.get("ONLY_DOTENV").map(String::as_str), Some("yes"));
+    }
+
+    #[test]
+    fn setup_retry_delay_doubles_up_to_the_cap() {
+        let base = Duration::from_secs(5);
+        assert_eq!(setup_retry_delay(base, 1), Duration::from_secs(5));
+        assert_eq!(setup_retry_delay(base, 2), Duration::from_secs(10));
+        assert_eq!(setup_retry_delay(base, 3), Duration::from_secs(20));
+        assert_eq!(setup_retry_delay(base, 5), SETUP_RETRY_MAX_DELAY);
+        assert_eq!(setup_retry_delay(base, 40), SETUP_RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn ensure_bind_source_in_repo_accepts_missing_paths_inside_the_repo() {
+        let repo = TempDir::new();
+        assert!(ensure_bind_source_in_repo(&repo.dir.join("data/cache"), &repo.dir).is_ok());
+        assert!(ensure_bind_source_in_repo(&repo.dir.join("../outside"), &repo.dir).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ensure_bind_source_in_repo_rejects_symlinked_parents() {
+        let repo = TempDir::new();
+        let outside = TempDir::new();
+        std::os::unix::fs::symlink(&outside.dir, repo.dir.join("link")).unwrap();
+        let source = repo.dir.join("link/not-created-yet");
+        assert!(ensure_bind_source_in_repo(&source, &repo.dir).is_err());
+    }
 }
@@ -1,12 +1,29 @@
 use git2::{
-    build::RepoBuilder, BranchType, Cred, CredentialType, FetchOptions, IndexAddOption,
+    build::RepoBuilder, BranchType, Cred, CredentialType, ErrorCode, FetchOptions, IndexAddOption,
     PushOptions, RemoteCallbacks, Repository, RepositoryInitOptions, ResetType, Signature, Sort,
     StatusOptions,
 };
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+use tauri::{AppHandle, Emitter};
 use thiserror::Error;
 
+const CLONE_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+const BLAME_MAX_LINES: usize = 5000;
+const REPO_INFO_CACHE_TTL: Duration = Duration::from_secs(10);
+
+static REPO_INFO_CACHE: OnceLock<Mutex<HashMap<String, CachedRepoInfo>>> = OnceLock::new();
+/// How long a clone, push or pull may go without progress before it is failed.
+pub const DEFAULT_NETWORK_TIMEOUT: Duration = Duration::from_secs(300);
+const NETWORK_CANCEL_GRACE: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Error)]
 pub enum GitError {
     #[error("Git error: {0}")]
@@ -32,8 +49,11 @@ pub type GitResult<T> = Result<T, GitError>;
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CommitInfo {
     pub id: String,
+    pub short_id: String,
     pub author: String,
     pub message: String,
+    pub summary: String,
+    pub body: String,
     pub timestamp: i64,
 }
 
@@ -48,6 +68,9 @@ pub struct BranchInfo {
 pub struct FileStatus {
     pub path: String,
     pub status: String, // "modified", "added", "deleted", "renamed", "untracked"
+    pub staged: bool,
+    pub index_status: Option<String>, // change staged in the index, if any
+    pub worktree_status: Option<String>, // unstaged change in the working tree, if any
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -59,6 +82,137 @@ pub struct RepositoryInfo {
     pub status_files: Vec<FileStatus>,
 }
 
+/// How `push_to_remote` treats a remote branch that has diverged.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ForceMode {
+    /// Plain push; rejected unless it fast-forwards the remote.
+    #[default]
+    None,
+    /// Overwrite the remote branch unconditionally.
+    Force,
+    /// Overwrite only if the remote tip is still what our remote-tracking
+    /// branch last saw.
+    WithLease,
+}
+
+/// Credentials used to reach a remote. SSH URLs take a private key path;
+/// HTTPS URLs take an access token.
+#[derive(Clone)]
+pub enum GitAuth {
+    Ssh(String),
+    Token(String),
+}
+
+/// Options for `push_to_remote` beyond the remote and local branch.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PushSettings {
+    pub force: ForceMode,
+    /// Remote branch to push to. Defaults to the local branch name.
+    pub remote_branch: Option<String>,
+    /// Make the pushed remote branch the local branch's upstream, like
+    /// `git push -u`.
+    pub set_upstream: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CurrentBranchInfo {
+    /// Branch name, or "detached" when HEAD is not on a branch.
+    pub name: String,
+    pub detached: bool,
+    pub upstream: Option<String>,
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+}
+
+/// Lightweight per-repo status for dashboards. `ahead`/`behind` are relative
+/// to the head branch's upstream and None when it has none.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RepoStatusSummary {
+    pub path: String,
+    pub head_branch: String,
+    pub is_dirty: bool,
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct CloneProgressEvent {
+    path: String,
+    phase: String,
+    received_objects: usize,
+    indexed_objects: usize,
+    total_objects: usize,
+    received_bytes: usize,
+    checkout_completed: usize,
+    checkout_total: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Divergence {
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorktreeInfo {
+    pub name: String,
+    pub path: String,
+    pub branch: Option<String>,
+    pub locked: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RevertResult {
+    pub commit_id: Option<String>,
+    pub conflicts: Vec<String>,
+}
+
+/// A run of consecutive lines in the file as of HEAD last changed by one commit.
+/// Line numbers are 1-based and inclusive.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlameLine {
+    pub commit_id: String,
+    pub author: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlameResult {
+    pub lines: Vec<BlameLine>,
+    /// Set when the file is longer than the blame cap and only the leading
+    /// lines were blamed.
+    pub truncated: bool,
+}
+
+/// A conflicted index path with the blob id of each side. A side is None when
+/// the file does not exist there (e.g. added on one branch only).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConflictEntry {
+    pub path: String,
+    pub ancestor_id: Option<String>,
+    pub our_id: Option<String>,
+    pub their_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MergeResult {
+    /// The new HEAD, or None when already up to date or conflicted.
+    pub commit_id: Option<String>,
+    pub fast_forward: bool,
+    pub conflicts: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RebaseResult {
+    /// True when every commit was replayed and the rebase finished.
+    pub completed: bool,
+    /// Paths left conflicted by the step that stopped the rebase.
+    pub conflicts: Vec<String>,
+}
+
 fn resolve_reference_commit<'a>(
     repo: &'a Repository,
     reference: &str,
@@ -121,23 +275,325 @@ fn configure_ssh_callbacks(callbacks: &mut RemoteCallbacks, ssh_key_path: &str)
     });
 }
 
-pub fn clone_repository(url: &str, local_path: &str, ssh_key_path: &str) -> GitResult<()> {
-    if !Path::new(ssh_key_path).exists() {
-        return Err(GitError::Git("SSH key not found".to_string()));
+fn configure_auth_callbacks(callbacks: &mut RemoteCallbacks, auth: &GitAuth) {
+    match auth {
+        GitAuth::Ssh(ssh_key_path) => configure_ssh_callbacks(callbacks, ssh_key_path),
+        GitAuth::Token(token) => {
+            let token = token.clone();
+            callbacks.credentials(move |_, _, allowed_types| {
+                if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
+                    return Cred::userpass_plaintext("x-access-token", &token);
+                }
+                Err(git2::Error::from_str(
+                    "No supported HTTPS credentials available",
+                ))
+            });
+        }
+    }
+}
+
+/// Shared between a network worker and `run_with_network_timeout`. The worker
+/// records progress through it and stops once it is cancelled.
+#[derive(Clone)]
+struct NetworkControl {
+    cancelled: Arc<AtomicBool>,
+    last_progress: Arc<Mutex<Instant>>,
+}
+
+impl NetworkControl {
+    fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            last_progress: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
     }
 
+    /// Records progress and returns whether the operation should keep going,
+    /// matching the return convention of the libgit2 progress callbacks.
+    fn tick(&self) -> bool {
+        *self
+            .last_progress
+            .lock()
+            .unwrap_or_else(|err| err.into_inner()) = Instant::now();
+        !self.is_cancelled()
+    }
+
+    fn idle_for(&self) -> Duration {
+        self.last_progress
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .elapsed()
+    }
+}
+
+/// Records progress on sideband and push callbacks and makes the remote abort
+/// once `control` is cancelled, so a stalled worker stops at its next tick.
+fn configure_cancel_callbacks(callbacks: &mut RemoteCallbacks, control: NetworkControl) {
+    let push_control = control.clone();
+    callbacks.push_transfer_progress(move |_, _, _| {
+        push_control.tick();
+    });
+    callbacks.sideband_progress(move |_| control.tick());
+}
+
+/// Runs a network operation on a worker thread and fails it once it has made
+/// no progress for `stall_timeout`. On a stall the worker is cancelled and
+/// given `NETWORK_CANCEL_GRACE` to unwind before the error is returned, so it
+/// is not still writing when the caller retries.
+fn run_with_network_timeout<T, F>(operation: &str, stall_timeout: Duration, work: F) -> GitResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(NetworkControl) -> GitResult<T> + Send + 'static,
+{
+    let control = NetworkControl::new();
+    let worker_control = control.clone();
+    let (tx, rx) = mpsc::channel();
+    let worker = std::thread::spawn(move || {
+        let _ = tx.send(work(worker_control));
+    });
+    loop {
+        let remaining = stall_timeout.saturating_sub(control.idle_for());
+        match rx.recv_timeout(remaining) {
+            Ok(result) => {
+                let _ = worker.join();
+                return result;
+            }
+            Err(RecvTimeoutError::Timeout) if control.idle_for() < stall_timeout => continue,
+            Err(RecvTimeoutError::Timeout) => {
+                control.cancel();
+                match rx.recv_timeout(NETWORK_CANCEL_GRACE) {
+                    Ok(_) | Err(RecvTimeoutError::Disconnected) => {
+                        let _ = worker.join();
+                    }
+                    Err(RecvTimeoutError::Timeout) => {
+                        eprintln!(
+                            "[falck][git] {} worker did not stop within {}s of cancellation",
+                            operation,
+                            NETWORK_CANCEL_GRACE.as_secs()
+                        );
+                    }
+                }
+                return Err(GitError::Git(format!(
+                    "{} stalled with no progress for {} seconds. Check your network connection and try again.",
+                    operation,
+                    stall_timeout.as_secs()
+                )));
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = worker.join();
+                return Err(GitError::Git(format!("{} stopped unexpectedly", operation)));
+            }
+        }
+    }
+}
+
+fn emit_clone_progress(app: Option<&AppHandle>, payload: CloneProgressEvent) {
+    let Some(app) = app else {
+        return;
+    };
+    let _ = app.emit("git:clone-progress", payload);
+}
+
+pub fn clone_repository(
+    url: &str,
+    local_path: &str,
+    auth: &GitAuth,
+    branch: Option<&str>,
+    init_submodules: bool,
+    timeout: Duration,
+    app: Option<&AppHandle>,
+) -> GitResult<()> {
+    if let GitAuth::Ssh(ssh_key_path) = auth {
+        if !Path::new(ssh_key_path).exists() {
+            return Err(GitError::Git("SSH key not found".to_string()));
+        }
+    }
+
+    let url = url.to_string();
+    let local_path = local_path.to_string();
+    let auth = auth.clone();
+    let branch = branch.map(str::to_string);
+    let app = app.cloned();
+    let existed = Path::new(&local_path).exists();
+    run_with_network_timeout("Clone", timeout, move |control| {
+        let result = clone_repository_inner(
+            &url,
+            &local_path,
+            &auth,
+            branch.as_deref(),
+            init_submodules,
+            app.as_ref(),
+            control,
+        );
+        // Remove a partial clone so a retry starts from an empty directory.
+        if result.is_err() && !existed {
+            let _ = std::fs::remove_dir_all(&local_path);
+        }
+        result
+    })
+}
+
+fn clone_repository_inner(
+    url: &str,
+    local_path: &str,
+    auth: &GitAuth,
+    branch: Option<&str>,
+    init_submodules: bool,
+    app: Option<&AppHandle>,
+    control: NetworkControl,
+) -> GitResult<()> {
     let mut callbacks = RemoteCallbacks::new();
-    configure_ssh_callbacks(&mut callbacks, ssh_key_path);
+    configure_auth_callbacks(&mut callbacks, auth);
+    configure_cancel_callbacks(&mut callbacks, control.clone());
+
+    let mut last_transfer_emit: Option<Instant> = None;
+    let transfer_control = control.clone();
+    callbacks.transfer_progress(move |stats| {
+        if !transfer_control.tick() {
+            return false;
+        }
+        let done = stats.total_objects() > 0 && stats.indexed_objects() == stats.total_objects();
+        let due = last_transfer_emit
+            .map(|last| last.elapsed() >= CLONE_PROGRESS_INTERVAL)
+            .unwrap_or(true);
+        if due || done {
+            last_transfer_emit = Some(Instant::now());
+            emit_clone_progress(
+                app,
+                CloneProgressEvent {
+                    path: local_path.to_string(),
+                    phase: "receiving".to_string(),
+                    received_objects: stats.received_objects(),
+                    indexed_objects: stats.indexed_objects(),
+                    total_objects: stats.total_objects(),
+                    received_bytes: stats.received_bytes(),
+                    checkout_completed: 0,
+                    checkout_total: 0,
+                },
+            );
+        }
+        true
+    });
 
     let mut fetch_options = FetchOptions::new();
     fetch_options.remote_callbacks(callbacks);
 
+    let mut last_checkout_emit: Option<Instant> = None;
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    let checkout_control = control.clone();
+    checkout.progress(move |_, completed, total| {
+        checkout_control.tick();
+        let due = last_checkout_emit
+            .map(|last| last.elapsed() >= CLONE_PROGRESS_INTERVAL)
+            .unwrap_or(true);
+        if due || completed == total {
+            last_checkout_emit = Some(Instant::now());
+            emit_clone_progress(
+                app,
+                CloneProgressEvent {
+                    path: local_path.to_string(),
+                    phase: "checkout".to_string(),
+                    received_objects: 0,
+                    indexed_objects: 0,
+                    total_objects: 0,
+                    received_bytes: 0,
+                    checkout_completed: completed,
+                    checkout_total: total,
+                },
+            );
+        }
+    });
+
     let mut builder = RepoBuilder::new();
     builder.fetch_options(fetch_options);
-    builder.clone(url, Path::new(local_path))?;
+    builder.with_checkout(checkout);
+    if let Some(branch) = branch {
+        builder.branch(branch);
+    }
+    let repo = builder
+        .clone(url, Path::new(local_path))
+        .map_err(|err| match branch {
+            Some(branch) if err.code() == ErrorCode::NotFound => {
+                GitError::Git(format!("Branch '{}' does not exist on the remote", branch))
+            }
+            _ => GitError::from(err),
+        })?;
+
+    if init_submodules {
+        emit_clone_progress(
+            app,
+            CloneProgressEvent {
+                path: local_path.to_string(),
+                phase: "submodules".to_string(),
+                received_objects: 0,
+                indexed_objects: 0,
+                total_objects: 0,
+                received_bytes: 0,
+                checkout_completed: 0,
+                checkout_total: 0,
+            },
+        );
+        update_submodules_in(&repo, auth, &control)?;
+    }
     Ok(())
 }
 
+fn update_submodules_in(
+    repo: &Repository,
+    auth: &GitAuth,
+    control: &NetworkControl,
+) -> GitResult<usize> {
+    let mut updated = 0;
+    for mut submodule in repo.submodules()? {
+        let name = submodule.name().unwrap_or("unknown").to_string();
+        let mut callbacks = RemoteCallbacks::new();
+        configure_auth_callbacks(&mut callbacks, auth);
+        configure_cancel_callbacks(&mut callbacks, control.clone());
+        let mut fetch_options = FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+        let mut update_options = git2::SubmoduleUpdateOptions::new();
+        update_options.fetch(fetch_options);
+        submodule
+            .update(true, Some(&mut update_options))
+            .map_err(|err| {
+                GitError::Git(format!(
+                    "Submodule '{}' update failed: {}",
+                    name,
+                    err.message()
+                ))
+            })?;
+        updated += 1;
+        // Nested submodules are initialized as part of the same pass.
+        if let Ok(sub_repo) = submodule.open() {
+            updated += update_submodules_in(&sub_repo, auth, control)?;
+        }
+    }
+    Ok(updated)
+}
+
+/// Initializes and updates all submodules recursively. Returns how many were
+/// updated.
+pub fn update_submodules(path: &str, ssh_key_path: &str) -> GitResult<usize> {
+    let _invalidate = invalidate_on_return(path);
+    if !Path::new(ssh_key_path).exists() {
+        return Err(GitError::Git("SSH key not found".to_string()));
+    }
+    let repo = open_repository(path)?;
+    update_submodules_in(
+        &repo,
+        &GitAuth::Ssh(ssh_key_path.to_string()),
+        &NetworkControl::new(),
+    )
+}
+
 pub fn open_repository(path: &str) -> GitResult<Repository> {
     let repo = Repository::open(path).map_err(|_| GitError::RepositoryNotFound)?;
     Ok(repo)
@@ -150,6 +606,92 @@ pub fn init_repository(path: &str) -> GitResult<()> {
     Ok(())
 }
 
+/// Cheap snapshot of the repo state that `RepositoryInfo` depends on. Working
+/// tree edits don't show up here, which is why cache entries also expire.
+#[derive(Debug, Clone, PartialEq)]
+struct RepoFingerprint {
+    index_modified: Option<SystemTime>,
+    head: Option<String>,
+    head_target: Option<git2::Oid>,
+}
+
+struct CachedRepoInfo {
+    fingerprint: RepoFingerprint,
+    computed_at: Instant,
+    info: RepositoryInfo,
+}
+
+fn repo_fingerprint(repo: &Repository) -> RepoFingerprint {
+    let index_modified = std::fs::metadata(repo.path().join("index"))
+        .and_then(|meta| meta.modified())
+        .ok();
+    let head = repo.head().ok();
+    RepoFingerprint {
+        index_modified,
+        head: head
+            .as_ref()
+            .and_then(|head| head.name().map(str::to_string)),
+        head_target: head.as_ref().and_then(|head| head.target()),
+    }
+}
+
+fn repo_info_cache() -> &'static Mutex<HashMap<String, CachedRepoInfo>> {
+    REPO_INFO_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drops the cached `RepositoryInfo` for `path`.
+pub fn invalidate_repository_info(path: &str) {
+    if let Ok(mut cache) = repo_info_cache().lock() {
+        cache.remove(path);
+    }
+}
+
+/// Invalidates the cached `RepositoryInfo` when dropped, so mutating
+/// operations clear it on every return path.
+struct RepoInfoInvalidation<'a>(&'a str);
+
+impl Drop for RepoInfoInvalidation<'_> {
+    fn drop(&mut self) {
+        invalidate_repository_info(self.0);
+    }
+}
+
+fn invalidate_on_return(path: &str) -> RepoInfoInvalidation<'_> {
+    RepoInfoInvalidation(path)
+}
+
+/// Like `get_repository_info`, but reuses the last result while HEAD and the
+/// index are unchanged and the entry is younger than the cache TTL. `refresh`
+/// forces a recompute.
+pub fn get_repository_info_cached(path: &str, refresh: bool) -> GitResult<RepositoryInfo> {
+    let repo = open_repository(path)?;
+    let fingerprint = repo_fingerprint(&repo);
+    if !refresh {
+        if let Ok(cache) = repo_info_cache().lock() {
+            if let Some(cached) = cache.get(path) {
+                if cached.fingerprint == fingerprint
+                    && cached.computed_at.elapsed() < REPO_INFO_CACHE_TTL
+                {
+                    return Ok(cached.info.clone());
+                }
+            }
+        }
+    }
+
+    let info = get_repository_info(path)?;
+    if let Ok(mut cache) = repo_info_cache().lock() {
+        cache.insert(
+            path.to_string(),
+            CachedRepoInfo {
+                fingerprint,
+                computed_at: Instant::now(),
+                info: info.clone(),
+            },
+        );
+    }
+    Ok(info)
+}
+
 pub fn get_repository_info(path: &str) -> GitResult<RepositoryInfo> {
     let repo = open_repository(path)?;
 
@@ -196,9 +738,13 @@ pub fn get_repository_info(path: &str) -> GitResult<RepositoryInfo> {
             "unknown"
         };
 
+        let index_status = index_status_label(status);
         status_files.push(FileStatus {
             path: entry.path().unwrap_or("unknown").to_string(),
             status: status_str.to_string(),
+            staged: index_status.is_some(),
+            index_status: index_status.map(str::to_string),
+            worktree_status: worktree_status_label(status).map(str::to_string),
         });
     }
 
@@ -213,6 +759,173 @@ pub fn get_repository_info(path: &str) -> GitResult<RepositoryInfo> {
     })
 }
 
+/// Lists paths excluded by .gitignore. Ignored directories are reported once
+/// (with a trailing slash) rather than expanded, so `node_modules/` stays cheap.
+pub fn get_ignored_files(path: &str) -> GitResult<Vec<String>> {
+    let repo = open_repository(path)?;
+    let mut status_options = StatusOptions::new();
+    status_options
+        .include_untracked(false)
+        .include_ignored(true)
+        .recurse_ignored_dirs(false)
+        .include_unmodified(false);
+    let statuses = repo.statuses(Some(&mut status_options))?;
+    let mut ignored = Vec::new();
+    for entry in statuses.iter() {
+        if !entry.status().is_ignored() {
+            continue;
+        }
+        if let Some(path) = entry.path() {
+            ignored.push(path.to_string());
+        }
+    }
+    ignored.sort();
+    Ok(ignored)
+}
+
+pub fn is_path_ignored(path: &str, file_path: &str) -> GitResult<bool> {
+    let repo = open_repository(path)?;
+    Ok(repo.is_path_ignored(Path::new(file_path))?)
+}
+
+/// Upstream name and ahead/behind counts for a local branch, or None when it
+/// has no upstream.
+fn upstream_status(
+    repo: &Repository,
+    branch_name: &str,
+) -> GitResult<Option<(String, usize, usize)>> {
+    let branch = repo.find_branch(branch_name, BranchType::Local)?;
+    let Ok(upstream) = branch.upstream() else {
+        return Ok(None);
+    };
+    let (Some(local), Some(remote)) = (branch.get().target(), upstream.get().target()) else {
+        return Ok(None);
+    };
+    let upstream_name = upstream.name()?.unwrap_or("unknown").to_string();
+    let (ahead, behind) = repo.graph_ahead_behind(local, remote)?;
+    Ok(Some((upstream_name, ahead, behind)))
+}
+
+pub fn get_current_branch(path: &str) -> GitResult<CurrentBranchInfo> {
+    let repo = open_repository(path)?;
+    let head = repo.head().ok();
+    let branch_name = head
+        .as_ref()
+        .filter(|head| head.is_branch())
+        .and_then(|head| head.shorthand())
+        .map(str::to_string);
+    let Some(name) = branch_name else {
+        return Ok(CurrentBranchInfo {
+            name: "detached".to_string(),
+            detached: true,
+            upstream: None,
+            ahead: None,
+            behind: None,
+        });
+    };
+
+    let (upstream, ahead, behind) = match upstream_status(&repo, &name)? {
+        Some((upstream, ahead, behind)) => (Some(upstream), Some(ahead), Some(behind)),
+        None => (None, None, None),
+    };
+    Ok(CurrentBranchInfo {
+        name,
+        detached: false,
+        upstream,
+        ahead,
+        behind,
+    })
+}
+
+fn repo_status_summary(path: &str) -> GitResult<RepoStatusSummary> {
+    let repo = open_repository(path)?;
+    let head = repo.head().ok();
+    let head_branch = head
+        .as_ref()
+        .and_then(|head| head.shorthand())
+        .unwrap_or("detached")
+        .to_string();
+
+    let mut status_options = StatusOptions::new();
+    status_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(false)
+        .include_ignored(false)
+        .include_unmodified(false);
+    let is_dirty = !repo.statuses(Some(&mut status_options))?.is_empty();
+
+    let (ahead, behind) = match head.as_ref().filter(|head| head.is_branch()) {
+        Some(_) => match upstream_status(&repo, &head_branch)? {
+            Some((_, ahead, behind)) => (Some(ahead), Some(behind)),
+            None => (None, None),
+        },
+        None => (None, None),
+    };
+
+    Ok(RepoStatusSummary {
+        path: path.to_string(),
+        head_branch,
+        is_dirty,
+        ahead,
+        behind,
+    })
+}
+
+/// Summarizes many repos at once, spreading the work over a few threads.
+/// Paths that aren't git repositories are skipped; results keep input order.
+pub fn get_repos_status(paths: &[String]) -> Vec<RepoStatusSummary> {
+    let workers = std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(4)
+        .min(paths.len().max(1));
+    let chunk_size = paths.len().div_ceil(workers).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .filter_map(|path| repo_status_summary(path).ok())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    })
+}
+
+fn index_status_label(status: git2::Status) -> Option<&'static str> {
+    if status.is_index_new() {
+        Some("added")
+    } else if status.is_index_modified() || status.is_index_typechange() {
+        Some("modified")
+    } else if status.is_index_deleted() {
+        Some("deleted")
+    } else if status.is_index_renamed() {
+        Some("renamed")
+    } else {
+        None
+    }
+}
+
+fn worktree_status_label(status: git2::Status) -> Option<&'static str> {
+    if status.is_wt_new() {
+        Some("untracked")
+    } else if status.is_wt_modified() || status.is_wt_typechange() {
+        Some("modified")
+    } else if status.is_wt_deleted() {
+        Some("deleted")
+    } else if status.is_wt_renamed() {
+        Some("renamed")
+    } else {
+        None
+    }
+}
+
 pub fn has_commits(path: &str) -> GitResult<bool> {
     let repo = open_repository(path)?;
     let has_commit = repo
@@ -230,6 +943,7 @@ pub fn current_branch(path: &str) -> GitResult<String> {
 }
 
 pub fn ensure_main_branch(path: &str) -> GitResult<String> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let head = repo.head()?;
     let current = head.shorthand().unwrap_or("main").to_string();
@@ -255,6 +969,24 @@ pub fn ensure_main_branch(path: &str) -> GitResult<String> {
 // Commit Operations
 // ============================================================================
 
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    let id = commit.id().to_string();
+    let message = commit.message().unwrap_or("").to_string();
+    let (summary, body) = match message.split_once('\n') {
+        Some((summary, body)) => (summary.trim_end(), body.trim()),
+        None => (message.trim_end(), ""),
+    };
+    CommitInfo {
+        short_id: id.chars().take(7).collect(),
+        id,
+        author: commit.author().name().unwrap_or("unknown").to_string(),
+        summary: summary.to_string(),
+        body: body.to_string(),
+        message,
+        timestamp: commit.time().seconds(),
+    }
+}
+
 pub fn get_commit_history(path: &str, max_count: usize) -> GitResult<Vec<CommitInfo>> {
     let repo = open_repository(path)?;
     let mut revwalk = repo.revwalk()?;
@@ -265,16 +997,7 @@ pub fn get_commit_history(path: &str, max_count: usize) -> GitResult<Vec<CommitI
     for oid in revwalk.take(max_count) {
         if let Ok(oid) = oid {
             let commit = repo.find_commit(oid)?;
-            let author = commit.author().name().unwrap_or("unknown").to_string();
-            let message = commit.message().unwrap_or("").to_string();
-            let timestamp = commit.time().seconds();
-
-            commits.push(CommitInfo {
-                id: oid.to_string(),
-                author,
-                message,
-                timestamp,
-            });
+            commits.push(commit_info(&commit));
         }
     }
 
@@ -298,72 +1021,588 @@ pub fn get_project_history(
     for oid in revwalk.take(max_count) {
         if let Ok(oid) = oid {
             let commit = repo.find_commit(oid)?;
-            let author = commit.author().name().unwrap_or("unknown").to_string();
-            let message = commit.message().unwrap_or("").to_string();
-            let timestamp = commit.time().seconds();
-
-            commits.push(CommitInfo {
-                id: oid.to_string(),
-                author,
-                message,
-                timestamp,
-            });
+            commits.push(commit_info(&commit));
         }
     }
 
     Ok(commits)
 }
 
-pub fn reset_to_commit(path: &str, commit_id: &str) -> GitResult<()> {
+/// Walks history from HEAD and keeps commits whose message and author contain
+/// the given filters (case-insensitive). Stops after `max_count` matches.
+pub fn search_commits(
+    path: &str,
+    query: Option<&str>,
+    author: Option<&str>,
+    max_count: usize,
+) -> GitResult<Vec<CommitInfo>> {
     let repo = open_repository(path)?;
-    let oid = git2::Oid::from_str(commit_id)?;
-    let commit = repo.find_commit(oid)?;
-    repo.reset(commit.as_object(), ResetType::Hard, None)?;
-    Ok(())
-}
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.set_sorting(Sort::TIME)?;
 
-pub fn discard_changes(path: &str) -> GitResult<()> {
-    let repo = open_repository(path)?;
-    let head = repo.head()?.peel_to_commit()?;
-    repo.reset(head.as_object(), ResetType::Hard, None)?;
+    let normalize = |value: Option<&str>| {
+        value
+            .map(|value| value.trim().to_lowercase())
+            .filter(|value| !value.is_empty())
+    };
+    let query = normalize(query);
+    let author_filter = normalize(author);
 
-    let workdir = repo
-        .workdir()
-        .ok_or_else(|| GitError::Git("Repository workdir not found".to_string()))?;
-    let mut status_options = StatusOptions::new();
-    status_options
-        .include_untracked(true)
-        .recurse_untracked_dirs(true)
-        .include_ignored(false)
-        .include_unmodified(false);
-    let statuses = repo.statuses(Some(&mut status_options))?;
-    for entry in statuses.iter() {
-        if entry.status().is_wt_new() {
-            if let Some(path) = entry.path() {
-                let full_path = workdir.join(path);
-                if full_path.is_dir() {
-                    std::fs::remove_dir_all(full_path)?;
-                } else {
-                    std::fs::remove_file(full_path)?;
-                }
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        if commits.len() >= max_count {
+            break;
+        }
+        let Ok(oid) = oid else {
+            continue;
+        };
+        let commit = repo.find_commit(oid)?;
+        if let Some(query) = &query {
+            let message = commit.message().unwrap_or("").to_lowercase();
+            if !message.contains(query) {
+                continue;
             }
         }
+        if let Some(filter) = &author_filter {
+            let signature = commit.author();
+            let name = signature.name().unwrap_or("unknown").to_lowercase();
+            let email = signature.email().unwrap_or("").to_lowercase();
+            if !name.contains(filter) && !email.contains(filter) {
+                continue;
+            }
+        }
+
+        commits.push(commit_info(&commit));
     }
 
-    Ok(())
+    Ok(commits)
 }
 
-fn get_git_user_config(path: &str) -> (String, String) {
-    let name = open_repository(path)
-        .ok()
-        .and_then(|repo| repo.config().ok())
-        .and_then(|cfg| cfg.get_string("user.name").ok())
-        .or_else(|| {
-            git2::Config::open_default()
-                .ok()
-                .and_then(|c| c.get_string("user.name").ok())
-        })
-        .unwrap_or_else(|| "User".to_string());
+/// Walks history from HEAD and keeps commits that touched `file`: its blob
+/// differs from every parent's (so merges that took one side unchanged are
+/// skipped). Stops after `max_count` matches.
+pub fn get_file_history(path: &str, file: &str, max_count: usize) -> GitResult<Vec<CommitInfo>> {
+    let repo = open_repository(path)?;
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.set_sorting(Sort::TIME)?;
+
+    let file_path = Path::new(file);
+    let blob_id = |commit: &git2::Commit| -> GitResult<Option<git2::Oid>> {
+        let tree = commit.tree()?;
+        Ok(tree.get_path(file_path).ok().map(|entry| entry.id()))
+    };
+
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        if commits.len() >= max_count {
+            break;
+        }
+        let Ok(oid) = oid else {
+            continue;
+        };
+        let commit = repo.find_commit(oid)?;
+        let current = blob_id(&commit)?;
+        let touched = if commit.parent_count() == 0 {
+            current.is_some()
+        } else {
+            let mut touched = true;
+            for parent in commit.parents() {
+                if blob_id(&parent)? == current {
+                    touched = false;
+                    break;
+                }
+            }
+            touched
+        };
+        if touched {
+            commits.push(commit_info(&commit));
+        }
+    }
+
+    Ok(commits)
+}
+
+pub fn blame_file(path: &str, file: &str) -> GitResult<BlameResult> {
+    let repo = open_repository(path)?;
+    let head = repo.head()?.peel_to_commit()?;
+    let entry = head
+        .tree()?
+        .get_path(Path::new(file))
+        .map_err(|_| GitError::Git(format!("File '{}' is not tracked at HEAD", file)))?;
+    let object = entry.to_object(&repo)?;
+    let blob = object
+        .as_blob()
+        .ok_or_else(|| GitError::Git(format!("'{}' is not a file", file)))?;
+    if blob.is_binary() {
+        return Err(GitError::Git(format!("'{}' is a binary file", file)));
+    }
+    let total_lines = blob.content().iter().filter(|byte| **byte == b'\n').count();
+
+    let mut options = git2::BlameOptions::new();
+    let truncated = total_lines > BLAME_MAX_LINES;
+    if truncated {
+        options.min_line(1).max_line(BLAME_MAX_LINES);
+    }
+    let blame = repo.blame_file(Path::new(file), Some(&mut options))?;
+
+    let mut lines = Vec::new();
+    for hunk in blame.iter() {
+        let line_count = hunk.lines_in_hunk();
+        if line_count == 0 {
+            continue;
+        }
+        let signature = hunk.final_signature();
+        let start_line = hunk.final_start_line();
+        lines.push(BlameLine {
+            commit_id: hunk.final_commit_id().to_string(),
+            author: signature.name().unwrap_or("unknown").to_string(),
+            start_line,
+            end_line: start_line + line_count - 1,
+        });
+    }
+
+    Ok(BlameResult { lines, truncated })
+}
+
+pub fn get_branch_divergence(path: &str, base_branch: &str) -> GitResult<Divergence> {
+    let repo = open_repository(path)?;
+    let base_commit = resolve_reference_commit(&repo, base_branch)?;
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let (ahead, behind) = repo.graph_ahead_behind(head_commit.id(), base_commit.id())?;
+    Ok(Divergence { ahead, behind })
+}
+
+pub fn get_file_at_commit(path: &str, commit_id: &str, file_path: &str) -> GitResult<String> {
+    let repo = open_repository(path)?;
+    let oid = git2::Oid::from_str(commit_id)?;
+    let commit = repo.find_commit(oid)?;
+    let tree = commit.tree()?;
+    let entry = tree.get_path(Path::new(file_path)).map_err(|_| {
+        GitError::Git(format!(
+            "File '{}' not found in commit {}",
+            file_path, commit_id
+        ))
+    })?;
+    let object = entry.to_object(&repo)?;
+    let blob = object
+        .as_blob()
+        .ok_or_else(|| GitError::Git(format!("'{}' is not a file", file_path)))?;
+    if blob.is_binary() {
+        return Err(GitError::Git(format!("'{}' is a binary file", file_path)));
+    }
+    let content = std::str::from_utf8(blob.content())
+        .map_err(|_| GitError::Git(format!("'{}' is not valid UTF-8", file_path)))?;
+    Ok(content.to_string())
+}
+
+pub fn reset_to_commit(path: &str, commit_id: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let oid = git2::Oid::from_str(commit_id)?;
+    let commit = repo.find_commit(oid)?;
+    repo.reset(commit.as_object(), ResetType::Hard, None)?;
+    Ok(())
+}
+
+pub fn revert_commit(path: &str, commit_id: &str) -> GitResult<RevertResult> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let oid = git2::Oid::from_str(commit_id)?;
+    let commit = repo.find_commit(oid)?;
+    repo.revert(&commit, None)?;
+
+    let mut index = repo.index()?;
+    if index.has_conflicts() {
+        return Ok(RevertResult {
+            commit_id: None,
+            conflicts: index_conflict_paths(&index)?,
+        });
+    }
+
+    let subject = commit.summary().unwrap_or("").to_string();
+    let message = format!(
+        "Revert \"{}\"\n\nThis reverts commit {}.\n",
+        subject,
+        commit.id()
+    );
+
+    let (name, email) = get_git_user_config(path);
+    let signature = Signature::now(&name, &email)?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+    let head = repo.head()?.peel_to_commit()?;
+    let new_oid = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &[&head],
+    )?;
+    repo.cleanup_state()?;
+
+    Ok(RevertResult {
+        commit_id: Some(new_oid.to_string()),
+        conflicts: Vec::new(),
+    })
+}
+
+fn index_conflict_paths(index: &git2::Index) -> GitResult<Vec<String>> {
+    let mut conflicts = Vec::new();
+    for conflict in index.conflicts()? {
+        let conflict = conflict?;
+        let entry = conflict.our.or(conflict.their).or(conflict.ancestor);
+        if let Some(entry) = entry {
+            conflicts.push(String::from_utf8_lossy(&entry.path).to_string());
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Merges a local branch into the current branch. Fast-forwards when possible,
+/// otherwise creates a merge commit; conflicts are left in the working tree.
+pub fn merge_branch(path: &str, branch: &str) -> GitResult<MergeResult> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    if repo.state() != git2::RepositoryState::Clean {
+        return Err(GitError::Git(
+            "Another operation is in progress. Finish or abort it first.".to_string(),
+        ));
+    }
+    let their_commit = resolve_reference_commit(&repo, branch)?;
+    let annotated = repo.find_annotated_commit(their_commit.id())?;
+    let (analysis, _) = repo.merge_analysis(&[&annotated])?;
+
+    if analysis.is_up_to_date() {
+        return Ok(MergeResult {
+            commit_id: None,
+            fast_forward: false,
+            conflicts: Vec::new(),
+        });
+    }
+
+    let mut head = repo.head()?;
+    if analysis.is_fast_forward() {
+        repo.checkout_tree(their_commit.as_object(), None)?;
+        let reflog = format!("merge {}: Fast-forward", branch);
+        head.set_target(their_commit.id(), &reflog)?;
+        return Ok(MergeResult {
+            commit_id: Some(their_commit.id().to_string()),
+            fast_forward: true,
+            conflicts: Vec::new(),
+        });
+    }
+
+    repo.merge(&[&annotated], None, None)?;
+    let mut index = repo.index()?;
+    if index.has_conflicts() {
+        return Ok(MergeResult {
+            commit_id: None,
+            fast_forward: false,
+            conflicts: index_conflict_paths(&index)?,
+        });
+    }
+
+    let (name, email) = get_git_user_config(path);
+    let signature = Signature::now(&name, &email)?;
+    let tree_id = index.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+    let head_commit = head.peel_to_commit()?;
+    let current = head.shorthand().unwrap_or("HEAD").to_string();
+    let message = format!("Merge branch '{}' into {}", branch, current);
+    let new_oid = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &[&head_commit, &their_commit],
+    )?;
+    repo.cleanup_state()?;
+
+    Ok(MergeResult {
+        commit_id: Some(new_oid.to_string()),
+        fast_forward: false,
+        conflicts: Vec::new(),
+    })
+}
+
+/// Backs out of a conflicted merge, cherry-pick, revert or rebase, restoring
+/// the index and working tree to HEAD.
+pub fn abort_merge(path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    match repo.state() {
+        git2::RepositoryState::Clean => Err(GitError::Git("No merge in progress".to_string())),
+        git2::RepositoryState::Rebase
+        | git2::RepositoryState::RebaseInteractive
+        | git2::RepositoryState::RebaseMerge => {
+            repo.open_rebase(None)?.abort()?;
+            Ok(())
+        }
+        _ => {
+            let head = repo.head()?.peel_to_commit()?;
+            repo.reset(head.as_object(), ResetType::Hard, None)?;
+            repo.cleanup_state()?;
+            Ok(())
+        }
+    }
+}
+
+pub fn list_conflicts(path: &str) -> GitResult<Vec<ConflictEntry>> {
+    let repo = open_repository(path)?;
+    let index = repo.index()?;
+    if !index.has_conflicts() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for conflict in index.conflicts()? {
+        let conflict = conflict?;
+        let entry_path = conflict
+            .our
+            .as_ref()
+            .or(conflict.their.as_ref())
+            .or(conflict.ancestor.as_ref())
+            .map(|entry| String::from_utf8_lossy(&entry.path).to_string());
+        let Some(entry_path) = entry_path else {
+            continue;
+        };
+        let blob_id = |entry: Option<git2::IndexEntry>| entry.map(|entry| entry.id.to_string());
+        entries.push(ConflictEntry {
+            path: entry_path,
+            ancestor_id: blob_id(conflict.ancestor),
+            our_id: blob_id(conflict.our),
+            their_id: blob_id(conflict.their),
+        });
+    }
+    Ok(entries)
+}
+
+/// Marks a conflicted path as resolved by replacing its conflict entries with
+/// the working-tree version. A file deleted in the working tree is resolved
+/// as a deletion.
+pub fn resolve_conflict(path: &str, file_path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::Git("Repository workdir not found".to_string()))?;
+    let mut index = repo.index()?;
+    let file = Path::new(file_path);
+    if index.conflict_get(file).is_err() {
+        return Err(GitError::Git(format!("'{}' is not conflicted", file_path)));
+    }
+    index.conflict_remove(file)?;
+    if workdir.join(file).exists() {
+        index.add_path(file)?;
+    } else {
+        index.remove_path(file)?;
+    }
+    index.write()?;
+    Ok(())
+}
+
+/// Commits the rebase step that was just applied. Steps whose changes are
+/// already in the new base produce no commit and are skipped.
+fn commit_rebase_step(rebase: &mut git2::Rebase, signature: &Signature) -> GitResult<()> {
+    match rebase.commit(None, signature, None) {
+        Ok(_) => Ok(()),
+        Err(err) if err.code() == ErrorCode::Applied => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Applies the remaining rebase steps, stopping at the first one that
+/// conflicts and leaving the rebase in progress.
+fn drive_rebase(
+    repo: &Repository,
+    rebase: &mut git2::Rebase,
+    signature: &Signature,
+) -> GitResult<RebaseResult> {
+    while let Some(operation) = rebase.next() {
+        operation?;
+        let index = repo.index()?;
+        if index.has_conflicts() {
+            return Ok(RebaseResult {
+                completed: false,
+                conflicts: index_conflict_paths(&index)?,
+            });
+        }
+        commit_rebase_step(rebase, signature)?;
+    }
+    rebase.finish(Some(signature))?;
+    Ok(RebaseResult {
+        completed: true,
+        conflicts: Vec::new(),
+    })
+}
+
+/// Replays the current branch's commits on top of `base_branch`.
+pub fn rebase_onto(path: &str, base_branch: &str) -> GitResult<RebaseResult> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    if repo.state() != git2::RepositoryState::Clean {
+        return Err(GitError::Git(
+            "Another operation is in progress. Finish or abort it first.".to_string(),
+        ));
+    }
+    let base_commit = resolve_reference_commit(&repo, base_branch)?;
+    let upstream = repo.find_annotated_commit(base_commit.id())?;
+    let branch = repo.reference_to_annotated_commit(&repo.head()?)?;
+
+    let (name, email) = get_git_user_config(path);
+    let signature = Signature::now(&name, &email)?;
+    let mut rebase = repo.rebase(Some(&branch), Some(&upstream), None, None)?;
+    drive_rebase(&repo, &mut rebase, &signature)
+}
+
+/// Commits the resolved step of an in-progress rebase and replays the rest.
+pub fn rebase_continue(path: &str) -> GitResult<RebaseResult> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let mut rebase = repo
+        .open_rebase(None)
+        .map_err(|_| GitError::Git("No rebase in progress".to_string()))?;
+    let index = repo.index()?;
+    if index.has_conflicts() {
+        return Ok(RebaseResult {
+            completed: false,
+            conflicts: index_conflict_paths(&index)?,
+        });
+    }
+
+    let (name, email) = get_git_user_config(path);
+    let signature = Signature::now(&name, &email)?;
+    if rebase.operation_current().is_some() {
+        commit_rebase_step(&mut rebase, &signature)?;
+    }
+    drive_rebase(&repo, &mut rebase, &signature)
+}
+
+pub fn rebase_abort(path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let mut rebase = repo
+        .open_rebase(None)
+        .map_err(|_| GitError::Git("No rebase in progress".to_string()))?;
+    rebase.abort()?;
+    Ok(())
+}
+
+pub fn discard_changes(path: &str, remove_untracked: bool) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let head = repo.head()?.peel_to_commit()?;
+    repo.reset(head.as_object(), ResetType::Hard, None)?;
+    if !remove_untracked {
+        return Ok(());
+    }
+
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::Git("Repository workdir not found".to_string()))?;
+    let mut status_options = StatusOptions::new();
+    status_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false)
+        .include_unmodified(false);
+    let statuses = repo.statuses(Some(&mut status_options))?;
+    for entry in statuses.iter() {
+        if entry.status().is_wt_new() {
+            if let Some(path) = entry.path() {
+                let full_path = workdir.join(path);
+                if full_path.is_dir() {
+                    std::fs::remove_dir_all(full_path)?;
+                } else {
+                    std::fs::remove_file(full_path)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves a repo-relative path inside `workdir`, rejecting anything that
+/// would land outside it (absolute paths, `..`, or symlinked parents).
+fn path_in_workdir(workdir: &Path, file_path: &str) -> GitResult<PathBuf> {
+    let relative = Path::new(file_path);
+    let escapes = relative
+        .components()
+        .any(|component| !matches!(component, std::path::Component::Normal(_)));
+    if file_path.trim().is_empty() || escapes {
+        return Err(GitError::Git(format!(
+            "Path '{}' is not inside the repository",
+            file_path
+        )));
+    }
+    let full_path = workdir.join(relative);
+    let root = std::fs::canonicalize(workdir)?;
+    let parent = full_path.parent().unwrap_or(workdir);
+    if parent.exists() && !std::fs::canonicalize(parent)?.starts_with(&root) {
+        return Err(GitError::Git(format!(
+            "Path '{}' is not inside the repository",
+            file_path
+        )));
+    }
+    Ok(full_path)
+}
+
+pub fn discard_file(path: &str, file_path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| GitError::Git("Repository workdir not found".to_string()))?
+        .to_path_buf();
+    let full_path = path_in_workdir(&workdir, file_path)?;
+    let status = repo.status_file(Path::new(file_path))?;
+    let head = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let in_head = head
+        .as_ref()
+        .and_then(|commit| commit.tree().ok())
+        .map(|tree| tree.get_path(Path::new(file_path)).is_ok())
+        .unwrap_or(false);
+
+    if !in_head {
+        // Untracked or newly added: drop it from the index and delete it.
+        if status.is_index_new() {
+            let mut index = repo.index()?;
+            index.remove_path(Path::new(file_path))?;
+            index.write()?;
+        }
+        if full_path.is_dir() {
+            std::fs::remove_dir_all(full_path)?;
+        } else if full_path.exists() {
+            std::fs::remove_file(full_path)?;
+        }
+        return Ok(());
+    }
+
+    if let Some(head) = &head {
+        repo.reset_default(Some(head.as_object()), [file_path])?;
+    }
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.path(file_path).force();
+    repo.checkout_head(Some(&mut checkout))?;
+    Ok(())
+}
+
+fn get_git_user_config(path: &str) -> (String, String) {
+    let name = open_repository(path)
+        .ok()
+        .and_then(|repo| repo.config().ok())
+        .and_then(|cfg| cfg.get_string("user.name").ok())
+        .or_else(|| {
+            git2::Config::open_default()
+                .ok()
+                .and_then(|c| c.get_string("user.name").ok())
+        })
+        .unwrap_or_else(|| "User".to_string());
     let email = open_repository(path)
         .ok()
         .and_then(|repo| repo.config().ok())
@@ -377,12 +1616,172 @@ fn get_git_user_config(path: &str) -> (String, String) {
     (name, email)
 }
 
+fn get_git_config_string(repo: &Repository, key: &str) -> Option<String> {
+    repo.config()
+        .ok()
+        .and_then(|cfg| cfg.get_string(key).ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn commit_signing_configured(repo: &Repository) -> bool {
+    repo.config()
+        .ok()
+        .and_then(|cfg| cfg.get_bool("commit.gpgsign").ok())
+        .unwrap_or(false)
+}
+
+fn run_signing_command(mut command: Command, buffer: &str, program: &str) -> GitResult<String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::Git(format!("Failed to run {}: {}", program, e)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(buffer.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitError::Git(format!(
+            "Commit signing failed: {}",
+            stderr.trim()
+        )));
+    }
+    let signature = String::from_utf8_lossy(&output.stdout).to_string();
+    if signature.trim().is_empty() {
+        return Err(GitError::Git(
+            "Commit signing produced an empty signature".to_string(),
+        ));
+    }
+    Ok(signature)
+}
+
+fn sign_commit_buffer(repo: &Repository, buffer: &str) -> GitResult<String> {
+    let format = get_git_config_string(repo, "gpg.format").unwrap_or_else(|| "openpgp".to_string());
+    let signing_key = get_git_config_string(repo, "user.signingkey");
+
+    match format.as_str() {
+        "ssh" => {
+            let key = signing_key.ok_or_else(|| {
+                GitError::Git("user.signingkey must be set for SSH commit signing".to_string())
+            })?;
+            let program = get_git_config_string(repo, "gpg.ssh.program")
+                .unwrap_or_else(|| "ssh-keygen".to_string());
+            let literal_key = key.strip_prefix("key::").unwrap_or(&key);
+            let (key_path, temp_key) = if literal_key.starts_with("ssh-") {
+                let temp_path = std::env::temp_dir()
+                    .join(format!("falck-signing-key-{}.pub", std::process::id()));
+                std::fs::write(&temp_path, literal_key)?;
+                (temp_path.clone(), Some(temp_path))
+            } else {
+                (PathBuf::from(&key), None)
+            };
+            let mut command = Command::new(&program);
+            command
+                .arg("-Y")
+                .arg("sign")
+                .arg("-n")
+                .arg("git")
+                .arg("-f")
+                .arg(&key_path);
+            let result = run_signing_command(command, buffer, &program);
+            if let Some(temp_path) = temp_key {
+                let _ = std::fs::remove_file(temp_path);
+            }
+            result
+        }
+        "openpgp" | "x509" => {
+            let default_program = if format == "x509" { "gpgsm" } else { "gpg" };
+            let program = get_git_config_string(repo, &format!("gpg.{}.program", format))
+                .or_else(|| get_git_config_string(repo, "gpg.program"))
+                .unwrap_or_else(|| default_program.to_string());
+            let mut command = Command::new(&program);
+            command.arg("--status-fd=2").arg("-bsa");
+            if let Some(key) = signing_key {
+                command.arg("-u").arg(key);
+            }
+            run_signing_command(command, buffer, &program)
+        }
+        other => Err(GitError::Git(format!(
+            "Unsupported gpg.format '{}'",
+            other
+        ))),
+    }
+}
+
+fn update_head_to(repo: &Repository, oid: git2::Oid, log_message: &str) -> GitResult<()> {
+    let head = repo.find_reference("HEAD")?;
+    match head.symbolic_target() {
+        Some(target) => {
+            repo.reference(target, oid, true, log_message)?;
+        }
+        None => repo.set_head_detached(oid)?,
+    }
+    Ok(())
+}
+
+fn is_trailer_line(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(key, value)| {
+        !key.is_empty()
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && value.starts_with(' ')
+    })
+}
+
+/// Appends `Key: value` trailers to a commit message, joining an existing
+/// trailer block when the last paragraph already is one. Trailers that are
+/// already present are not repeated.
+fn append_trailers(message: &str, trailers: &[(String, String)]) -> GitResult<String> {
+    let mut result = message.trim_end().to_string();
+    let existing: Vec<String> = result
+        .lines()
+        .filter(|line| is_trailer_line(line))
+        .map(str::to_string)
+        .collect();
+    let mut lines = Vec::new();
+    for (key, value) in trailers {
+        let key = key.trim();
+        let value = value.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(GitError::Git(format!("Invalid trailer key '{}'", key)));
+        }
+        if value.is_empty() || value.contains('\n') {
+            return Err(GitError::Git(format!(
+                "Invalid value for trailer '{}'",
+                key
+            )));
+        }
+        let line = format!("{}: {}", key, value);
+        if !existing.contains(&line) && !lines.contains(&line) {
+            lines.push(line);
+        }
+    }
+    if lines.is_empty() {
+        return Ok(message.to_string());
+    }
+
+    let last_paragraph = result.rsplit("\n\n").next().unwrap_or("");
+    let has_trailer_block = result.contains("\n\n")
+        && !last_paragraph.trim().is_empty()
+        && last_paragraph.lines().all(is_trailer_line);
+    result.push_str(if has_trailer_block { "\n" } else { "\n\n" });
+    result.push_str(&lines.join("\n"));
+    result.push('\n');
+    Ok(result)
+}
+
 pub fn create_commit(
     path: &str,
     message: &str,
     author_name: &str,
     author_email: &str,
+    sign: bool,
+    trailers: &[(String, String)],
+    sign_off: bool,
 ) -> GitResult<String> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
 
     let (name, email) = if author_name.trim().is_empty() || author_email.trim().is_empty() {
@@ -392,6 +1791,12 @@ pub fn create_commit(
     };
 
     let signature = Signature::now(&name, &email)?;
+    let mut trailers = trailers.to_vec();
+    if sign_off {
+        trailers.push(("Signed-off-by".to_string(), format!("{} <{}>", name, email)));
+    }
+    let message = append_trailers(message, &trailers)?;
+    let message = message.as_str();
     let tree_id = {
         let mut index = repo.index()?;
         index.write_tree()?
@@ -399,23 +1804,26 @@ pub fn create_commit(
 
     let tree = repo.find_tree(tree_id)?;
     let parent_commit = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
-    let oid = if let Some(parent) = parent_commit {
-        repo.commit(
-            Some("HEAD"),
-            &signature,
-            &signature,
-            message,
-            &tree,
-            &[&parent],
-        )?
-    } else {
-        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[])?
-    };
+    let parents: Vec<&git2::Commit> = parent_commit.iter().collect();
+
+    if sign || commit_signing_configured(&repo) {
+        let buffer = repo.commit_create_buffer(&signature, &signature, message, &tree, &parents)?;
+        let content = buffer
+            .as_str()
+            .ok_or_else(|| GitError::Git("Commit buffer is not valid UTF-8".to_string()))?;
+        let commit_signature = sign_commit_buffer(&repo, content)?;
+        let oid = repo.commit_signed(content, &commit_signature, None)?;
+        update_head_to(&repo, oid, &format!("commit: {}", message.lines().next().unwrap_or("")))?;
+        return Ok(oid.to_string());
+    }
+
+    let oid = repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)?;
 
     Ok(oid.to_string())
 }
 
 pub fn stage_file(path: &str, file_path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let mut index = repo.index()?;
     index.add_path(Path::new(file_path))?;
@@ -424,6 +1832,7 @@ pub fn stage_file(path: &str, file_path: &str) -> GitResult<()> {
 }
 
 pub fn stage_all(path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let mut index = repo.index()?;
     index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
@@ -432,10 +1841,73 @@ pub fn stage_all(path: &str) -> GitResult<()> {
 }
 
 pub fn unstage_file(path: &str, file_path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
-    let mut index = repo.index()?;
-    index.remove_path(Path::new(file_path))?;
-    index.write()?;
+    match repo.head().ok().and_then(|head| head.peel_to_commit().ok()) {
+        // Like `git reset <file>`: tracked files go back to their HEAD entry
+        // and files missing from HEAD drop out of the index.
+        Some(head) => repo.reset_default(Some(head.as_object()), [file_path])?,
+        None => {
+            let mut index = repo.index()?;
+            index.remove_path(Path::new(file_path))?;
+            index.write()?;
+        }
+    }
+    Ok(())
+}
+
+fn staged_paths(repo: &Repository) -> GitResult<Vec<PathBuf>> {
+    let mut status_options = StatusOptions::new();
+    status_options
+        .include_untracked(false)
+        .include_ignored(false);
+    let statuses = repo.statuses(Some(&mut status_options))?;
+    let staged = git2::Status::INDEX_NEW
+        | git2::Status::INDEX_MODIFIED
+        | git2::Status::INDEX_DELETED
+        | git2::Status::INDEX_RENAMED
+        | git2::Status::INDEX_TYPECHANGE;
+    let mut paths = Vec::new();
+    for entry in statuses.iter() {
+        if !entry.status().intersects(staged) {
+            continue;
+        }
+        if let Some(delta) = entry.head_to_index() {
+            for file in [delta.old_file(), delta.new_file()] {
+                if let Some(path) = file.path() {
+                    if !paths.iter().any(|existing: &PathBuf| existing == path) {
+                        paths.push(path.to_path_buf());
+                    }
+                }
+            }
+        }
+    }
+    Ok(paths)
+}
+
+pub fn unstage_all(path: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let paths = staged_paths(&repo)?;
+    if paths.is_empty() {
+        return Ok(());
+    }
+
+    match repo.head().ok().and_then(|head| head.peel_to_commit().ok()) {
+        Some(head) => {
+            // Reset the index entries back to HEAD; the working tree is untouched.
+            repo.reset_default(Some(head.as_object()), paths.iter())?;
+        }
+        None => {
+            // No commits yet, so everything staged is new and unstaging
+            // means dropping it from the index.
+            let mut index = repo.index()?;
+            for path in &paths {
+                index.remove_path(path)?;
+            }
+            index.write()?;
+        }
+    }
     Ok(())
 }
 
@@ -444,6 +1916,7 @@ pub fn unstage_file(path: &str, file_path: &str) -> GitResult<()> {
 // ============================================================================
 
 pub fn create_branch(path: &str, branch_name: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let head = repo.head()?;
     let commit = head.peel_to_commit()?;
@@ -452,13 +1925,27 @@ pub fn create_branch(path: &str, branch_name: &str) -> GitResult<()> {
 }
 
 pub fn delete_branch(path: &str, branch_name: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
     branch.delete()?;
     Ok(())
 }
 
+pub fn rename_branch(path: &str, old_name: &str, new_name: &str, force: bool) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let mut branch = repo.find_branch(old_name, BranchType::Local)?;
+    let was_head = branch.is_head();
+    branch.rename(new_name, force)?;
+    if was_head {
+        repo.set_head(&format!("refs/heads/{}", new_name))?;
+    }
+    Ok(())
+}
+
 pub fn checkout_branch(path: &str, branch_name: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let refname = format!("refs/heads/{}", branch_name);
     repo.set_head(&refname)?;
@@ -466,6 +1953,93 @@ pub fn checkout_branch(path: &str, branch_name: &str) -> GitResult<()> {
     Ok(())
 }
 
+pub fn checkout_commit(path: &str, commit_id: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let oid = git2::Oid::from_str(commit_id)?;
+    let commit = repo.find_commit(oid)?;
+    repo.set_head_detached(commit.id())?;
+    repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))?;
+    Ok(())
+}
+
+// ============================================================================
+// Worktree Operations
+// ============================================================================
+
+pub fn list_worktrees(path: &str) -> GitResult<Vec<WorktreeInfo>> {
+    let repo = open_repository(path)?;
+    let names = repo.worktrees()?;
+    let mut worktrees = Vec::new();
+    for name in names.iter().flatten() {
+        let worktree = repo.find_worktree(name)?;
+        let branch = Repository::open_from_worktree(&worktree)
+            .ok()
+            .and_then(|wt_repo| {
+                wt_repo
+                    .head()
+                    .ok()
+                    .and_then(|head| head.shorthand().map(str::to_string))
+            });
+        let locked = matches!(
+            worktree.is_locked(),
+            Ok(git2::WorktreeLockStatus::Locked(_))
+        );
+        worktrees.push(WorktreeInfo {
+            name: name.to_string(),
+            path: worktree.path().to_string_lossy().to_string(),
+            branch,
+            locked,
+        });
+    }
+    Ok(worktrees)
+}
+
+/// Adds a worktree at `dest` checked out on `branch`, creating the branch
+/// from HEAD when it does not exist yet.
+pub fn add_worktree(path: &str, name: &str, branch: &str, dest: &str) -> GitResult<WorktreeInfo> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let local_branch = match repo.find_branch(branch, BranchType::Local) {
+        Ok(existing) => existing,
+        Err(_) => {
+            let target = resolve_reference_commit(&repo, "HEAD")?;
+            repo.branch(branch, &target, false)?
+        }
+    };
+    let reference = local_branch.into_reference();
+    let mut options = git2::WorktreeAddOptions::new();
+    options.reference(Some(&reference));
+    let worktree = repo.worktree(name, Path::new(dest), Some(&options))?;
+    Ok(WorktreeInfo {
+        name: name.to_string(),
+        path: worktree.path().to_string_lossy().to_string(),
+        branch: Some(branch.to_string()),
+        locked: false,
+    })
+}
+
+/// Removes a worktree and its checkout directory. Locked worktrees are left
+/// alone.
+pub fn remove_worktree(path: &str, name: &str) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let worktree = repo.find_worktree(name)?;
+    if let Ok(git2::WorktreeLockStatus::Locked(reason)) = worktree.is_locked() {
+        let reason = reason.unwrap_or_default();
+        let message = if reason.trim().is_empty() {
+            format!("Worktree '{}' is locked", name)
+        } else {
+            format!("Worktree '{}' is locked: {}", name, reason.trim())
+        };
+        return Err(GitError::Git(message));
+    }
+    let mut options = git2::WorktreePruneOptions::new();
+    options.valid(true).working_tree(true);
+    worktree.prune(Some(&mut options))?;
+    Ok(())
+}
+
 // ============================================================================
 // Push/Pull Operations
 // ============================================================================
@@ -475,21 +2049,188 @@ pub fn push_to_remote(
     remote_name: &str,
     branch_name: &str,
     ssh_key_path: &str,
+    settings: &PushSettings,
+    timeout: Duration,
 ) -> GitResult<()> {
     if !Path::new(ssh_key_path).exists() {
         return Err(GitError::Git("SSH key not found".to_string()));
     }
 
-    let repo = open_repository(path)?;
-    let mut remote = repo.find_remote(remote_name)?;
-    let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch_name);
+    let path = path.to_string();
+    let remote_name = remote_name.to_string();
+    let branch_name = branch_name.to_string();
+    let ssh_key_path = ssh_key_path.to_string();
+    let settings = settings.clone();
+    run_with_network_timeout("Push", timeout, move |control| {
+        push_to_remote_inner(
+            &path,
+            &remote_name,
+            &branch_name,
+            &ssh_key_path,
+            &settings,
+            control,
+        )
+    })
+}
+
+/// The remote tip a force push expects to overwrite, checked against the
+/// tip the server advertises on the push connection itself.
+struct PushLease {
+    /// `remote/branch`, for the error message.
+    label: String,
+    /// Last fetched tip; `None` means we have never seen the branch.
+    expected: Option<git2::Oid>,
+}
+
+fn short_oid(oid: Option<git2::Oid>) -> String {
+    oid.map(|oid| oid.to_string().chars().take(7).collect::<String>())
+        .unwrap_or_else(|| "nothing".to_string())
+}
 
+/// Pushes a single refspec, turning a per-ref rejection from the server into
+/// an error (libgit2 otherwise reports those as success). With a `lease`, the
+/// push is aborted before any objects are sent if the remote moved.
+fn push_refspec(
+    remote: &mut git2::Remote,
+    refspec: &str,
+    ssh_key_path: &str,
+    control: NetworkControl,
+    lease: Option<PushLease>,
+) -> GitResult<()> {
     let mut callbacks = RemoteCallbacks::new();
     configure_ssh_callbacks(&mut callbacks, ssh_key_path);
+    configure_cancel_callbacks(&mut callbacks, control);
+    let mut rejection: Option<String> = None;
+    callbacks.push_update_reference(|refname, status| {
+        if let Some(status) = status {
+            rejection = Some(format!("Push of {} was rejected: {}", refname, status));
+        }
+        Ok(())
+    });
+    let mut lease_error: Option<String> = None;
+    if let Some(lease) = &lease {
+        callbacks.push_negotiation(|updates| {
+            for update in updates {
+                let actual = Some(update.src()).filter(|oid| !oid.is_zero());
+                if actual.is_some() && actual != lease.expected {
+                    lease_error = Some(format!(
+                        "Remote moved: '{}' is at {} but we last saw {}. Pull and review the new commits before force-pushing.",
+                        lease.label,
+                        short_oid(actual),
+                        short_oid(lease.expected)
+                    ));
+                    return Err(git2::Error::from_str("remote moved since last fetch"));
+                }
+            }
+            Ok(())
+        });
+    }
 
     let mut push_options = PushOptions::new();
     push_options.remote_callbacks(callbacks);
-    remote.push(&[&refspec], Some(&mut push_options))?;
+    let result = remote.push(&[refspec], Some(&mut push_options));
+    drop(push_options);
+    if let Some(message) = lease_error {
+        return Err(GitError::Git(message));
+    }
+    result?;
+    match rejection {
+        Some(message) => Err(GitError::Git(message)),
+        None => Ok(()),
+    }
+}
+
+/// Deletes `branch_name` on the remote by pushing an empty source, then drops
+/// the matching remote-tracking branch.
+pub fn delete_remote_branch(
+    path: &str,
+    remote_name: &str,
+    branch_name: &str,
+    ssh_key_path: &str,
+    timeout: Duration,
+) -> GitResult<()> {
+    if !Path::new(ssh_key_path).exists() {
+        return Err(GitError::Git("SSH key not found".to_string()));
+    }
+
+    let path = path.to_string();
+    let remote_name = remote_name.to_string();
+    let branch_name = branch_name.to_string();
+    let ssh_key_path = ssh_key_path.to_string();
+    run_with_network_timeout("Delete remote branch", timeout, move |control| {
+        let _invalidate = invalidate_on_return(&path);
+        let repo = open_repository(&path)?;
+        let mut remote = repo.find_remote(&remote_name)?;
+        let refspec = format!(":refs/heads/{}", branch_name);
+        push_refspec(&mut remote, &refspec, &ssh_key_path, control, None)?;
+
+        let tracking = format!("refs/remotes/{}/{}", remote_name, branch_name);
+        if let Ok(mut reference) = repo.find_reference(&tracking) {
+            reference.delete()?;
+        }
+        Ok(())
+    })
+}
+
+fn push_to_remote_inner(
+    path: &str,
+    remote_name: &str,
+    branch_name: &str,
+    ssh_key_path: &str,
+    settings: &PushSettings,
+    control: NetworkControl,
+) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
+    let repo = open_repository(path)?;
+    let mut remote = repo.find_remote(remote_name)?;
+    let remote_branch = settings
+        .remote_branch
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(branch_name);
+    let force = settings.force;
+
+    let lease = (force == ForceMode::WithLease).then(|| {
+        let tracking = format!("refs/remotes/{}/{}", remote_name, remote_branch);
+        PushLease {
+            label: format!("{}/{}", remote_name, remote_branch),
+            expected: repo
+                .find_reference(&tracking)
+                .ok()
+                .and_then(|reference| reference.target()),
+        }
+    });
+
+    let prefix = if force == ForceMode::None { "" } else { "+" };
+    let refspec = format!(
+        "{}refs/heads/{}:refs/heads/{}",
+        prefix, branch_name, remote_branch
+    );
+
+    push_refspec(&mut remote, &refspec, ssh_key_path, control, lease)?;
+
+    if settings.set_upstream {
+        // Record the pushed tip as the remote-tracking branch so the new
+        // upstream resolves before the next fetch.
+        let pushed = repo
+            .find_branch(branch_name, BranchType::Local)?
+            .get()
+            .peel_to_commit()?
+            .id();
+        repo.reference(
+            &format!("refs/remotes/{}/{}", remote_name, remote_branch),
+            pushed,
+            true,
+            &format!("push: updating {}/{}", remote_name, remote_branch),
+        )?;
+        let mut config = repo.config()?;
+        config.set_str(&format!("branch.{}.remote", branch_name), remote_name)?;
+        config.set_str(
+            &format!("branch.{}.merge", branch_name),
+            &format!("refs/heads/{}", remote_branch),
+        )?;
+    }
     Ok(())
 }
 
@@ -498,16 +2239,37 @@ pub fn pull_from_remote(
     remote_name: &str,
     branch_name: &str,
     ssh_key_path: &str,
+    timeout: Duration,
 ) -> GitResult<()> {
     if !Path::new(ssh_key_path).exists() {
         return Err(GitError::Git("SSH key not found".to_string()));
     }
 
+    let path = path.to_string();
+    let remote_name = remote_name.to_string();
+    let branch_name = branch_name.to_string();
+    let ssh_key_path = ssh_key_path.to_string();
+    run_with_network_timeout("Pull", timeout, move |control| {
+        pull_from_remote_inner(&path, &remote_name, &branch_name, &ssh_key_path, control)
+    })
+}
+
+fn pull_from_remote_inner(
+    path: &str,
+    remote_name: &str,
+    branch_name: &str,
+    ssh_key_path: &str,
+    control: NetworkControl,
+) -> GitResult<()> {
+    let _invalidate = invalidate_on_return(path);
     let repo = open_repository(path)?;
     let mut remote = repo.find_remote(remote_name)?;
 
     let mut callbacks = RemoteCallbacks::new();
     configure_ssh_callbacks(&mut callbacks, ssh_key_path);
+    let transfer_control = control.clone();
+    callbacks.transfer_progress(move |_| transfer_control.tick());
+    configure_cancel_callbacks(&mut callbacks, control);
 
     let mut fetch_options = FetchOptions::new();
     fetch_options.remote_callbacks(callbacks);
@@ -573,3 +2335,130 @@ pub fn add_or_update_remote(path: &str, name: &str, url: &str) -> GitResult<()>
     }
     Ok(())
 }
+
+pub fn remove_remote(path: &str, name: &str) -> GitResult<()> {
+    let repo = open_repository(path)?;
+    if repo.find_remote(name).is_err() {
+        return Err(GitError::Git(format!("Remote '{}' does not exist.", name)));
+    }
+    repo.remote_delete(name)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    struct TempRepo {
+        dir: PathBuf,
+    }
+
+    impl TempRepo {
+        fn new() -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "falck-git-test-{}-{}",
+                std::process::id(),
+                TEMP_COUNTER.fetch_add(1, Ordering::SeqCst)
+            ));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            init_repository(dir.to_str().unwrap()).unwrap();
+            Self { dir }
+        }
+
+        fn path(&self) -> &str {
+            self.dir.to_str().unwrap()
+        }
+
+        fn write(&self, file: &str, contents: &str) {
+            std::fs::write(self.dir.join(file), contents).unwrap();
+        }
+
+        fn commit_all(&self) {
+            let repo = open_repository(self.path()).unwrap();
+            let mut index = repo.index().unwrap();
+            index
+                .add_all(["*"].iter(), IndexAddOption::DEFAULT, None)
+                .unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let signature = Signature::now("Falck Test", "test@example.com").unwrap();
+            let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            repo.commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                "test",
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        }
+
+        fn index_entry(&self, file: &str) -> Option<git2::Oid> {
+            let repo = open_repository(self.path()).unwrap();
+            let index = repo.index().unwrap();
+            index.get_path(Path::new(file), 0).map(|entry| entry.id)
+        }
+    }
+
+    impl Drop for TempRepo {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn unstage_file_with_unborn_head_removes_index_entry() {
+        let repo = TempRepo::new();
+        repo.write("a.txt", "one");
+        stage_file(repo.path(), "a.txt").unwrap();
+        assert!(repo.index_entry("a.txt").is_some());
+
+        unstage_file(repo.path(), "a.txt").unwrap();
+
+        assert!(repo.index_entry("a.txt").is_none());
+        assert!(repo.dir.join("a.txt").exists());
+    }
+
+    #[test]
+    fn unstage_file_drops_newly_staged_path() {
+        let repo = TempRepo::new();
+        repo.write("a.txt", "one");
+        repo.commit_all();
+        repo.write("b.txt", "new");
+        stage_file(repo.path(), "b.txt").unwrap();
+
+        unstage_file(repo.path(), "b.txt").unwrap();
+
+        assert!(repo.index_entry("b.txt").is_none());
+        assert!(repo.index_entry("a.txt").is_some());
+        assert_eq!(
+            std::fs::read_to_string(repo.dir.join("b.txt")).unwrap(),
+            "new"
+        );
+    }
+
+    #[test]
+    fn unstage_file_restores_head_entry_for_modified_path() {
+        let repo = TempRepo::new();
+        repo.write("a.txt", "one");
+        repo.commit_all();
+        let committed = repo.index_entry("a.txt").unwrap();
+        repo.write("a.txt", "two");
+        stage_file(repo.path(), "a.txt").unwrap();
+        assert_ne!(repo.index_entry("a.txt"), Some(committed));
+
+        unstage_file(repo.path(), "a.txt").unwrap();
+
+        assert_eq!(repo.index_entry("a.txt"), Some(committed));
+        assert_eq!(
+            std::fs::read_to_string(repo.dir.join("a.txt")).unwrap(),
+            "two"
+        );
+    }
+}
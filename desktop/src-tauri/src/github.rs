@@ -1,17 +1,35 @@
-use reqwest::{header, Client, StatusCode};
+use reqwest::{header, Client, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, State};
 use tokio::time::{sleep, Duration, Instant};
 
+use crate::blocking::run_blocking;
+use crate::git;
 use crate::storage;
 
-const DEVICE_URL: &str = "https://github.com/login/device/code";
-const TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
-const API_BASE: &str = "https://api.github.com";
+const DEVICE_PATH: &str = "/login/device/code";
+const TOKEN_PATH: &str = "/login/oauth/access_token";
 const DEFAULT_SCOPE: &str = "repo write:public_key";
+const OAUTH_SCOPES_HEADER: &str = "x-oauth-scopes";
 const USER_AGENT: &str = "Falck";
 const GITHUB_API_VERSION: &str = "2022-11-28";
+const FORK_READY_TIMEOUT: Duration = Duration::from_secs(30);
+const FORK_READY_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Scope requested for each pending device code, so the poll checks the new
+/// token against what the user was actually asked to grant.
+static DEVICE_FLOW_SCOPES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+fn device_flow_scopes() -> std::sync::MutexGuard<'static, HashMap<String, String>> {
+    DEVICE_FLOW_SCOPES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeviceCodeResponse {
@@ -29,6 +47,13 @@ pub struct TokenInfo {
     pub scope: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubBaseUrls {
+    pub api_base_url: String,
+    pub oauth_base_url: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GithubUser {
     pub login: String,
@@ -60,6 +85,33 @@ pub struct GithubRepo {
     pub owner: GithubOwner,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GithubBranch {
+    pub name: String,
+    pub sha: String,
+    pub protected: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubBranchResponse {
+    name: String,
+    commit: GithubBranchCommit,
+    #[serde(default)]
+    protected: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubBranchCommit {
+    sha: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GithubReview {
+    pub id: u64,
+    pub state: String,
+    pub html_url: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GithubCreatePullRequestInput {
@@ -79,6 +131,84 @@ pub struct GithubPullRequest {
     pub url: String,
     pub state: String,
     pub title: String,
+    #[serde(default)]
+    pub draft: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GithubPullRequestDefaults {
+    pub base: String,
+    pub head: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubPullRequestRef {
+    #[serde(rename = "ref")]
+    ref_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubPullRequestPayload {
+    number: u64,
+    title: String,
+    state: String,
+    html_url: String,
+    head: GithubPullRequestRef,
+    base: GithubPullRequestRef,
+    user: Option<GithubOwner>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestSummary {
+    pub number: u64,
+    pub title: String,
+    pub state: String,
+    pub head_ref: String,
+    pub base_ref: String,
+    pub author: Option<String>,
+    pub html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCheckRun {
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    html_url: Option<String>,
+    details_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCheckRunsResponse {
+    check_runs: Vec<GithubCheckRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCommitStatus {
+    context: String,
+    state: String,
+    target_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCombinedStatus {
+    statuses: Vec<GithubCommitStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckItem {
+    pub name: String,
+    pub conclusion: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksSummary {
+    pub state: String,
+    pub checks: Vec<CheckItem>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -127,6 +257,59 @@ fn clear_token(app: &AppHandle) -> Result<(), String> {
     storage::clear_github_token(app)
 }
 
+fn api_base_url(app: &AppHandle) -> Result<String, String> {
+    storage::get_github_api_base_url(app)
+}
+
+fn oauth_base_url(app: &AppHandle) -> Result<String, String> {
+    storage::get_github_oauth_base_url(app)
+}
+
+/// Derives the OAuth host from an Enterprise API URL (`https://host/api/v3` -> `https://host`).
+fn derive_oauth_base_url(api_base_url: &str) -> String {
+    let trimmed = api_base_url.trim().trim_end_matches('/');
+    if trimmed == storage::DEFAULT_GITHUB_API_BASE_URL {
+        return storage::DEFAULT_GITHUB_OAUTH_BASE_URL.to_string();
+    }
+    trimmed
+        .strip_suffix("/api/v3")
+        .unwrap_or(trimmed)
+        .to_string()
+}
+
+/// Derives the GraphQL endpoint from the REST base (`https://host/api/v3` -> `https://host/api/graphql`).
+fn derive_graphql_url(api_base_url: &str) -> String {
+    let trimmed = api_base_url.trim().trim_end_matches('/');
+    match trimmed.strip_suffix("/api/v3") {
+        Some(host) => format!("{}/api/graphql", host),
+        None => format!("{}/graphql", trimmed),
+    }
+}
+
+/// Returns the stored token for an HTTPS clone of `url`, refusing hosts other
+/// than the configured GitHub instance so the token never leaves it.
+pub(crate) fn clone_token_for_url(app: &AppHandle, url: &str) -> Result<String, String> {
+    let parsed =
+        reqwest::Url::parse(url.trim()).map_err(|_| format!("Invalid clone URL '{}'", url))?;
+    if parsed.scheme() != "https" {
+        return Err("Token authentication requires an https:// clone URL.".to_string());
+    }
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| format!("Invalid clone URL '{}'", url))?;
+    let allowed = [api_base_url(app)?, oauth_base_url(app)?]
+        .iter()
+        .filter_map(|base| reqwest::Url::parse(base).ok())
+        .any(|base| base.host_str() == Some(host));
+    if !allowed {
+        return Err(format!(
+            "Refusing to send the GitHub token to {}. Use SSH or a URL on the configured GitHub host.",
+            host
+        ));
+    }
+    load_token(app)
+}
+
 fn build_api_headers(token: &str) -> header::HeaderMap {
     let mut headers = header::HeaderMap::new();
     headers.insert(
@@ -148,6 +331,56 @@ fn build_api_headers(token: &str) -> header::HeaderMap {
     headers
 }
 
+fn format_reset_time(reset_epoch: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let seconds_of_day = reset_epoch % 86_400;
+    let wait_minutes = reset_epoch.saturating_sub(now).div_ceil(60);
+    format!(
+        "{:02}:{:02}:{:02} UTC (in {} min)",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60,
+        wait_minutes
+    )
+}
+
+fn check_rate_limit(response: Response) -> Result<Response, String> {
+    let status = response.status();
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return Ok(response);
+    }
+
+    let headers = response.headers();
+    let header_value = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+
+    if header_value("x-ratelimit-remaining") == Some(0) {
+        return Err(match header_value("x-ratelimit-reset") {
+            Some(reset) => format!(
+                "GitHub rate limit exceeded, resets at {}",
+                format_reset_time(reset)
+            ),
+            None => "GitHub rate limit exceeded.".to_string(),
+        });
+    }
+
+    if let Some(retry_after) = header_value("retry-after") {
+        return Err(format!(
+            "GitHub rate limit exceeded, retry in {} seconds",
+            retry_after
+        ));
+    }
+
+    Ok(response)
+}
+
 fn parse_next_link(link_header: &str) -> Option<String> {
     for part in link_header.split(',') {
         let section = part.trim();
@@ -162,8 +395,24 @@ fn parse_next_link(link_header: &str) -> Option<String> {
     None
 }
 
+/// Percent-encodes a git ref for a URL path. `/` is kept so refs like
+/// `feature/login` reach the API as written.
+fn encode_ref_path(git_ref: &str) -> String {
+    let mut encoded = String::with_capacity(git_ref.len());
+    for byte in git_ref.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
 #[tauri::command]
 pub async fn github_start_device_flow(
+    app: AppHandle,
     client: State<'_, Client>,
     scope: Option<String>,
 ) -> Result<DeviceCodeResponse, String> {
@@ -179,25 +428,28 @@ pub async fn github_start_device_flow(
         })
         .unwrap_or_else(|| DEFAULT_SCOPE.to_string());
 
-    let params = [("client_id", client_id), ("scope", scope)];
+    let params = [("client_id", client_id), ("scope", scope.clone())];
     let response = client
-        .post(DEVICE_URL)
+        .post(format!("{}{}", oauth_base_url(&app)?, DEVICE_PATH))
         .header(header::ACCEPT, "application/json")
         .header(header::USER_AGENT, USER_AGENT)
         .form(&params)
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if !response.status().is_success() {
         let body = response.text().await.unwrap_or_default();
         return Err(format!("GitHub device flow failed: {}", body));
     }
 
-    response
+    let device = response
         .json::<DeviceCodeResponse>()
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    device_flow_scopes().insert(device.device_code.clone(), scope);
+    Ok(device)
 }
 
 #[tauri::command]
@@ -209,6 +461,11 @@ pub async fn github_poll_device_token(
     expires_in: u64,
 ) -> Result<TokenInfo, String> {
     let client_id = github_client_id()?;
+    let token_url = format!("{}{}", oauth_base_url(&app)?, TOKEN_PATH);
+    let requested_scope = device_flow_scopes()
+        .get(&device_code)
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_SCOPE.to_string());
     let start_time = Instant::now();
     let mut wait_seconds = interval.max(1);
 
@@ -229,13 +486,14 @@ pub async fn github_poll_device_token(
         ];
 
         let response = client
-            .post(TOKEN_URL)
+            .post(&token_url)
             .header(header::ACCEPT, "application/json")
             .header(header::USER_AGENT, USER_AGENT)
             .form(&params)
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
 
         let payload = response
             .json::<serde_json::Value>()
@@ -249,7 +507,12 @@ pub async fn github_poll_device_token(
                 .unwrap_or("bearer");
             let scope = payload.get("scope").and_then(|v| v.as_str()).unwrap_or("");
 
+            device_flow_scopes().remove(&device_code);
             store_token(&app, access_token)?;
+            if let Err(err) = ensure_token_scopes(&client, &app, &requested_scope).await {
+                let _ = clear_token(&app);
+                return Err(err);
+            }
             return Ok(TokenInfo {
                 token_type: token_type.to_string(),
                 scope: scope.to_string(),
@@ -279,6 +542,118 @@ pub async fn github_poll_device_token(
     }
 }
 
+/// Returns the scopes GitHub reports for the stored token, or `None` when the
+/// token type does not carry OAuth scopes (fine-grained tokens, GitHub Apps).
+async fn fetch_token_scopes(
+    client: &Client,
+    app: &AppHandle,
+) -> Result<Option<Vec<String>>, String> {
+    let token = load_token(app)?;
+    let api_base = api_base_url(app)?;
+    let response = client
+        .get(format!("{}/user", api_base))
+        .headers(build_api_headers(&token))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub scope check failed: {}", body));
+    }
+
+    Ok(response
+        .headers()
+        .get(OAUTH_SCOPES_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map(|scope| scope.trim().to_string())
+                .filter(|scope| !scope.is_empty())
+                .collect()
+        }))
+}
+
+/// Whether a granted scope includes `required`, following GitHub's scope
+/// hierarchy (`admin:` > `write:` > `read:`, and `repo`/`user` cover their
+/// sub-scopes).
+fn scope_covers(granted: &str, required: &str) -> bool {
+    if granted == required {
+        return true;
+    }
+    if required == "public_repo" {
+        return granted == "repo";
+    }
+    let Some((level, resource)) = required.split_once(':') else {
+        return false;
+    };
+    if granted == level {
+        return true;
+    }
+    let rank = |value: &str| match value {
+        "read" => 1,
+        "write" => 2,
+        "admin" => 3,
+        _ => 0,
+    };
+    match granted.split_once(':') {
+        Some((granted_level, granted_resource)) => {
+            granted_resource == resource && rank(level) > 0 && rank(granted_level) >= rank(level)
+        }
+        None => false,
+    }
+}
+
+fn missing_scopes<'a>(required: &'a str, granted: &[String]) -> Vec<&'a str> {
+    required
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|scope| !scope.is_empty())
+        .filter(|wanted| !granted.iter().any(|scope| scope_covers(scope, wanted)))
+        .collect()
+}
+
+/// Fails with a re-auth hint when the stored token lacks any of the
+/// `required` scopes.
+async fn ensure_token_scopes(
+    client: &Client,
+    app: &AppHandle,
+    required: &str,
+) -> Result<(), String> {
+    let Some(granted) = fetch_token_scopes(client, app).await? else {
+        return Ok(());
+    };
+    let missing = missing_scopes(required, &granted);
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "GitHub token is missing required scopes: {}. Sign in to GitHub again and grant access to continue.",
+        missing.join(", ")
+    ))
+}
+
+#[tauri::command]
+pub async fn github_check_token_scopes(
+    app: AppHandle,
+    client: State<'_, Client>,
+) -> Result<Vec<String>, String> {
+    Ok(fetch_token_scopes(&client, &app).await?.unwrap_or_default())
+}
+
+#[tauri::command]
+pub async fn github_verify_token_scopes(
+    app: AppHandle,
+    client: State<'_, Client>,
+) -> Result<(), String> {
+    ensure_token_scopes(&client, &app, DEFAULT_SCOPE).await
+}
+
 #[tauri::command]
 pub async fn github_has_token(app: AppHandle) -> Result<bool, String> {
     has_token(&app)
@@ -295,12 +670,14 @@ pub async fn github_get_user(
     client: State<'_, Client>,
 ) -> Result<GithubUser, String> {
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     let response = client
-        .get(format!("{}/user", API_BASE))
+        .get(format!("{}/user", api_base))
         .headers(build_api_headers(&token))
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if response.status() == StatusCode::UNAUTHORIZED {
         return Err("GitHub token is invalid or expired.".to_string());
@@ -323,11 +700,13 @@ pub async fn github_list_repos(
     client: State<'_, Client>,
 ) -> Result<Vec<GithubRepo>, String> {
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     let mut url = format!(
         "{}/user/repos?per_page=100&sort=updated&direction=desc&affiliation=owner,collaborator,organization_member",
-        API_BASE
+        api_base
     );
     let mut repos = Vec::new();
+    let mut seen_ids = HashSet::new();
 
     loop {
         let response = client
@@ -336,6 +715,7 @@ pub async fn github_list_repos(
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
 
         if response.status() == StatusCode::UNAUTHORIZED {
             return Err("GitHub token is invalid or expired.".to_string());
@@ -347,11 +727,16 @@ pub async fn github_list_repos(
         }
 
         let headers = response.headers().clone();
-        let mut page = response
+        let page = response
             .json::<Vec<GithubRepo>>()
             .await
             .map_err(|e| e.to_string())?;
-        repos.append(&mut page);
+        // A repo updated mid-pagination shifts between pages; keep its first position.
+        for repo in page {
+            if seen_ids.insert(repo.id) {
+                repos.push(repo);
+            }
+        }
 
         let next = headers
             .get(header::LINK)
@@ -389,9 +774,10 @@ pub async fn github_list_repo_collaborators(
     let repo = parts[1];
 
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     let mut url = format!(
         "{}/repos/{}/{}/collaborators?per_page=100",
-        API_BASE, owner, repo
+        api_base, owner, repo
     );
     let mut users = Vec::new();
 
@@ -402,6 +788,7 @@ pub async fn github_list_repo_collaborators(
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
 
         if response.status() == StatusCode::UNAUTHORIZED {
             return Err("GitHub token is invalid or expired.".to_string());
@@ -434,92 +821,336 @@ pub async fn github_list_repo_collaborators(
 }
 
 #[tauri::command]
-pub async fn github_request_reviewers(
+pub async fn github_list_branches(
     app: AppHandle,
     client: State<'_, Client>,
-    input: GithubRequestReviewersInput,
-) -> Result<GithubReviewRequestResult, String> {
-    let mut reviewers: Vec<String> = input
-        .reviewers
-        .into_iter()
-        .map(|reviewer| reviewer.trim().to_string())
-        .filter(|reviewer| !reviewer.is_empty())
-        .collect();
-    reviewers.sort();
-    reviewers.dedup();
-    if reviewers.is_empty() {
-        return Ok(GithubReviewRequestResult {
-            requested: Vec::new(),
-            skipped: Vec::new(),
-        });
-    }
-
-    let repo_full_name = input.repo_full_name.trim();
-    if repo_full_name.is_empty() {
+    owner: String,
+    repo: String,
+) -> Result<Vec<GithubBranch>, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
         return Err("GitHub repository is required.".to_string());
     }
 
-    let parts: Vec<&str> = repo_full_name
-        .split('/')
-        .filter(|part| !part.trim().is_empty())
-        .collect();
-    if parts.len() != 2 {
-        return Err("GitHub repository must be in the form owner/repo.".to_string());
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let mut url = format!(
+        "{}/repos/{}/{}/branches?per_page=100",
+        api_base, owner, repo
+    );
+    let mut branches = Vec::new();
+
+    loop {
+        let response = client
+            .get(&url)
+            .headers(build_api_headers(&token))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err("GitHub token is invalid or expired.".to_string());
+        }
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("GitHub branches fetch failed: {}", body));
+        }
+
+        let headers = response.headers().clone();
+        let page = response
+            .json::<Vec<GithubBranchResponse>>()
+            .await
+            .map_err(|e| e.to_string())?;
+        branches.extend(page.into_iter().map(|branch| GithubBranch {
+            name: branch.name,
+            sha: branch.commit.sha,
+            protected: branch.protected,
+        }));
+
+        let next = headers
+            .get(header::LINK)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_next_link);
+        if let Some(next_url) = next {
+            url = next_url;
+        } else {
+            break;
+        }
     }
-    let owner = parts[0];
-    let repo = parts[1];
 
-    let token = load_token(&app)?;
+    Ok(branches)
+}
+
+async fn fetch_repo(
+    client: &Client,
+    app: &AppHandle,
+    owner: &str,
+    repo: &str,
+) -> Result<GithubRepo, String> {
+    let token = load_token(app)?;
+    let api_base = api_base_url(app)?;
     let response = client
-        .post(format!(
-            "{}/repos/{}/{}/pulls/{}/requested_reviewers",
-            API_BASE, owner, repo, input.pull_number
-        ))
+        .get(format!("{}/repos/{}/{}", api_base, owner, repo))
         .headers(build_api_headers(&token))
-        .json(&json!({ "reviewers": reviewers.clone() }))
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if response.status() == StatusCode::UNAUTHORIZED {
         return Err("GitHub token is invalid or expired.".to_string());
     }
 
-    if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
-        let payload = response
-            .json::<serde_json::Value>()
-            .await
-            .unwrap_or_default();
-        let message = payload
-            .get("message")
-            .and_then(|value| value.as_str())
-            .unwrap_or("Reviewers could not be requested.");
-
-        let mut invalid: Vec<String> = Vec::new();
-        if let Some(errors) = payload.get("errors").and_then(|value| value.as_array()) {
-            for error in errors {
-                if let Some(msg) = error.get("message").and_then(|value| value.as_str()) {
-                    if let Some(list) = msg.split("Could not request reviews from:").nth(1) {
-                        for name in list.split(',') {
-                            let cleaned = name.trim();
-                            if !cleaned.is_empty() {
-                                invalid.push(cleaned.to_string());
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        invalid.sort();
-        invalid.dedup();
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(format!(
+            "GitHub repository {}/{} was not found.",
+            owner, repo
+        ));
+    }
 
-        if invalid.is_empty() {
-            return Err(message.to_string());
-        }
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub repository fetch failed: {}", body));
+    }
 
-        let valid: Vec<String> = reviewers
-            .iter()
-            .filter(|reviewer| !invalid.contains(reviewer))
+    response
+        .json::<GithubRepo>()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn github_repo_clone_url(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    protocol: String,
+) -> Result<String, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    let protocol = protocol.trim().to_lowercase();
+    if !matches!(protocol.as_str(), "ssh" | "https") {
+        return Err("Clone protocol must be either ssh or https.".to_string());
+    }
+
+    let repo = fetch_repo(&client, &app, owner, repo).await?;
+    Ok(if protocol == "ssh" {
+        repo.ssh_url
+    } else {
+        repo.clone_url
+    })
+}
+
+/// Suggests the base (the repository's default branch) and head (the local
+/// checked-out branch) for a new pull request.
+#[tauri::command]
+pub async fn github_pr_defaults(
+    app: AppHandle,
+    client: State<'_, Client>,
+    repo_path: String,
+    owner: String,
+    repo: String,
+) -> Result<GithubPullRequestDefaults, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+
+    let branch =
+        run_blocking(move || git::get_current_branch(&repo_path).map_err(|e| e.to_string()))
+            .await?;
+    if branch.detached {
+        return Err(
+            "HEAD is detached. Check out a branch before opening a pull request.".to_string(),
+        );
+    }
+
+    let remote = fetch_repo(&client, &app, owner, repo).await?;
+    let base = remote.default_branch.ok_or_else(|| {
+        format!(
+            "GitHub repository {}/{} has no default branch.",
+            owner, repo
+        )
+    })?;
+    Ok(GithubPullRequestDefaults {
+        base,
+        head: branch.name,
+    })
+}
+
+#[tauri::command]
+pub async fn github_list_pull_requests(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    state: Option<String>,
+) -> Result<Vec<PullRequestSummary>, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    let state = state
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "open".to_string());
+    if !matches!(state.as_str(), "open" | "closed" | "all") {
+        return Err("Pull request state must be one of open, closed, or all.".to_string());
+    }
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let mut url = format!(
+        "{}/repos/{}/{}/pulls?state={}&per_page=100",
+        api_base, owner, repo, state
+    );
+    let mut pulls = Vec::new();
+
+    loop {
+        let response = client
+            .get(&url)
+            .headers(build_api_headers(&token))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err("GitHub token is invalid or expired.".to_string());
+        }
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("GitHub pull request fetch failed: {}", body));
+        }
+
+        let headers = response.headers().clone();
+        let page = response
+            .json::<Vec<GithubPullRequestPayload>>()
+            .await
+            .map_err(|e| e.to_string())?;
+        pulls.extend(page.into_iter().map(|pull| PullRequestSummary {
+            number: pull.number,
+            title: pull.title,
+            state: pull.state,
+            head_ref: pull.head.ref_name,
+            base_ref: pull.base.ref_name,
+            author: pull.user.map(|user| user.login),
+            html_url: pull.html_url,
+        }));
+
+        let next = headers
+            .get(header::LINK)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_next_link);
+        if let Some(next_url) = next {
+            url = next_url;
+        } else {
+            break;
+        }
+    }
+
+    Ok(pulls)
+}
+
+#[tauri::command]
+pub async fn github_request_reviewers(
+    app: AppHandle,
+    client: State<'_, Client>,
+    input: GithubRequestReviewersInput,
+) -> Result<GithubReviewRequestResult, String> {
+    let mut reviewers: Vec<String> = input
+        .reviewers
+        .into_iter()
+        .map(|reviewer| reviewer.trim().to_string())
+        .filter(|reviewer| !reviewer.is_empty())
+        .collect();
+    reviewers.sort();
+    reviewers.dedup();
+    if reviewers.is_empty() {
+        return Ok(GithubReviewRequestResult {
+            requested: Vec::new(),
+            skipped: Vec::new(),
+        });
+    }
+
+    let repo_full_name = input.repo_full_name.trim();
+    if repo_full_name.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+
+    let parts: Vec<&str> = repo_full_name
+        .split('/')
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+    if parts.len() != 2 {
+        return Err("GitHub repository must be in the form owner/repo.".to_string());
+    }
+    let owner = parts[0];
+    let repo = parts[1];
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let response = client
+        .post(format!(
+            "{}/repos/{}/{}/pulls/{}/requested_reviewers",
+            api_base, owner, repo, input.pull_number
+        ))
+        .headers(build_api_headers(&token))
+        .json(&json!({ "reviewers": reviewers.clone() }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
+        let payload = response
+            .json::<serde_json::Value>()
+            .await
+            .unwrap_or_default();
+        let message = payload
+            .get("message")
+            .and_then(|value| value.as_str())
+            .unwrap_or("Reviewers could not be requested.");
+
+        let mut invalid: Vec<String> = Vec::new();
+        if let Some(errors) = payload.get("errors").and_then(|value| value.as_array()) {
+            for error in errors {
+                if let Some(msg) = error.get("message").and_then(|value| value.as_str()) {
+                    if let Some(list) = msg.split("Could not request reviews from:").nth(1) {
+                        for name in list.split(',') {
+                            let cleaned = name.trim();
+                            if !cleaned.is_empty() {
+                                invalid.push(cleaned.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        invalid.sort();
+        invalid.dedup();
+
+        if invalid.is_empty() {
+            return Err(message.to_string());
+        }
+
+        let valid: Vec<String> = reviewers
+            .iter()
+            .filter(|reviewer| !invalid.contains(reviewer))
             .cloned()
             .collect();
 
@@ -530,13 +1161,14 @@ pub async fn github_request_reviewers(
         let retry_response = client
             .post(format!(
                 "{}/repos/{}/{}/pulls/{}/requested_reviewers",
-                API_BASE, owner, repo, input.pull_number
+                api_base, owner, repo, input.pull_number
             ))
             .headers(build_api_headers(&token))
             .json(&json!({ "reviewers": valid }))
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        let retry_response = check_rate_limit(retry_response)?;
 
         if retry_response.status() == StatusCode::UNAUTHORIZED {
             return Err("GitHub token is invalid or expired.".to_string());
@@ -598,13 +1230,14 @@ pub async fn github_create_pull_request(
     let repo = parts[1];
 
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     let body = input
         .body
         .as_ref()
         .map(|value| value.trim().to_string())
         .filter(|value| !value.is_empty());
     let response = client
-        .post(format!("{}/repos/{}/{}/pulls", API_BASE, owner, repo))
+        .post(format!("{}/repos/{}/{}/pulls", api_base, owner, repo))
         .headers(build_api_headers(&token))
         .json(&json!({
             "title": input.title.trim(),
@@ -616,6 +1249,7 @@ pub async fn github_create_pull_request(
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if response.status() == StatusCode::UNAUTHORIZED {
         return Err("GitHub token is invalid or expired.".to_string());
@@ -644,6 +1278,379 @@ pub async fn github_create_pull_request(
         .map_err(|e| e.to_string())
 }
 
+fn check_severity(conclusion: &str) -> u8 {
+    match conclusion {
+        "success" | "neutral" | "skipped" => 0,
+        "pending" | "queued" | "in_progress" | "waiting" | "requested" => 1,
+        _ => 2,
+    }
+}
+
+#[tauri::command]
+pub async fn github_get_pr_checks(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    git_ref: String,
+) -> Result<ChecksSummary, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    let git_ref = git_ref.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    if git_ref.is_empty() {
+        return Err("Git ref is required.".to_string());
+    }
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let encoded_ref = encode_ref_path(git_ref);
+    let mut check_runs = Vec::new();
+    let mut url = format!(
+        "{}/repos/{}/{}/commits/{}/check-runs?per_page=100",
+        api_base, owner, repo, encoded_ref
+    );
+    loop {
+        let response = client
+            .get(&url)
+            .headers(build_api_headers(&token))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let response = check_rate_limit(response)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err("GitHub token is invalid or expired.".to_string());
+        }
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("GitHub check runs fetch failed: {}", body));
+        }
+
+        let headers = response.headers().clone();
+        let page = response
+            .json::<GithubCheckRunsResponse>()
+            .await
+            .map_err(|e| e.to_string())?;
+        check_runs.extend(page.check_runs);
+
+        let next = headers
+            .get(header::LINK)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_next_link);
+        if let Some(next_url) = next {
+            url = next_url;
+        } else {
+            break;
+        }
+    }
+
+    let response = client
+        .get(format!(
+            "{}/repos/{}/{}/commits/{}/status",
+            api_base, owner, repo, encoded_ref
+        ))
+        .headers(build_api_headers(&token))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub commit status fetch failed: {}", body));
+    }
+
+    let combined = response
+        .json::<GithubCombinedStatus>()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut checks = Vec::new();
+    for run in check_runs {
+        let conclusion = if run.status == "completed" {
+            run.conclusion.unwrap_or_else(|| "neutral".to_string())
+        } else {
+            run.status
+        };
+        checks.push(CheckItem {
+            name: run.name,
+            conclusion,
+            url: run.html_url.or(run.details_url),
+        });
+    }
+    for status in combined.statuses {
+        checks.push(CheckItem {
+            name: status.context,
+            conclusion: status.state,
+            url: status.target_url,
+        });
+    }
+
+    let worst = checks
+        .iter()
+        .map(|check| check_severity(&check.conclusion))
+        .max()
+        .unwrap_or(0);
+    let state = match worst {
+        0 => "success",
+        1 => "pending",
+        _ => "failure",
+    };
+
+    Ok(ChecksSummary {
+        state: state.to_string(),
+        checks,
+    })
+}
+
+#[tauri::command]
+pub async fn github_merge_pull_request(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    number: u64,
+    method: String,
+) -> Result<String, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    let method = method.trim().to_lowercase();
+    if !matches!(method.as_str(), "merge" | "squash" | "rebase") {
+        return Err("Merge method must be one of merge, squash, or rebase.".to_string());
+    }
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let response = client
+        .put(format!(
+            "{}/repos/{}/{}/pulls/{}/merge",
+            api_base, owner, repo, number
+        ))
+        .headers(build_api_headers(&token))
+        .json(&json!({ "merge_method": method }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if response.status() == StatusCode::METHOD_NOT_ALLOWED
+        || response.status() == StatusCode::CONFLICT
+    {
+        let payload = response
+            .json::<serde_json::Value>()
+            .await
+            .unwrap_or_default();
+        let message = payload
+            .get("message")
+            .and_then(|value| value.as_str())
+            .unwrap_or("Pull request is not mergeable.");
+        return Err(format!("Pull request #{} cannot be merged: {}", number, message));
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub pull request merge failed: {}", body));
+    }
+
+    let payload = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| e.to_string())?;
+    payload
+        .get("sha")
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+        .ok_or_else(|| "GitHub merge response did not include a commit sha.".to_string())
+}
+
+#[tauri::command]
+pub async fn github_mark_pr_ready(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    number: u64,
+) -> Result<(), String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let response = client
+        .get(format!(
+            "{}/repos/{}/{}/pulls/{}",
+            api_base, owner, repo, number
+        ))
+        .headers(build_api_headers(&token))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub pull request lookup failed: {}", body));
+    }
+
+    let payload = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !payload
+        .get("draft")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+    let node_id = payload
+        .get("node_id")
+        .and_then(|value| value.as_str())
+        .ok_or_else(|| "GitHub pull request response did not include a node id.".to_string())?;
+
+    // The REST API cannot clear the draft flag, so this goes through GraphQL.
+    let response = client
+        .post(derive_graphql_url(&api_base))
+        .headers(build_api_headers(&token))
+        .json(&json!({
+            "query": "mutation($id: ID!) { markPullRequestReadyForReview(input: { pullRequestId: $id }) { pullRequest { isDraft } } }",
+            "variables": { "id": node_id },
+        }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub mark ready for review failed: {}", body));
+    }
+
+    let payload = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(message) = payload
+        .get("errors")
+        .and_then(|value| value.as_array())
+        .and_then(|errors| errors.first())
+        .and_then(|value| value.get("message"))
+        .and_then(|value| value.as_str())
+    {
+        return Err(format!(
+            "Pull request #{} could not be marked ready: {}",
+            number, message
+        ));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn github_submit_review(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    number: u64,
+    event: String,
+    body: Option<String>,
+) -> Result<GithubReview, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    let event = event.trim().to_uppercase();
+    if !matches!(event.as_str(), "APPROVE" | "REQUEST_CHANGES" | "COMMENT") {
+        return Err(
+            "Review event must be one of APPROVE, REQUEST_CHANGES, or COMMENT.".to_string(),
+        );
+    }
+    let body = body
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    if body.is_none() && event != "APPROVE" {
+        return Err("A comment is required to request changes or comment.".to_string());
+    }
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let mut payload = json!({ "event": event });
+    if let Some(body) = body {
+        payload["body"] = json!(body);
+    }
+    let response = client
+        .post(format!(
+            "{}/repos/{}/{}/pulls/{}/reviews",
+            api_base, owner, repo, number
+        ))
+        .headers(build_api_headers(&token))
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
+        let payload = response
+            .json::<serde_json::Value>()
+            .await
+            .unwrap_or_default();
+        let message = payload
+            .get("errors")
+            .and_then(|value| value.as_array())
+            .and_then(|errors| errors.first())
+            .and_then(|value| value.as_str())
+            .or_else(|| payload.get("message").and_then(|value| value.as_str()))
+            .unwrap_or("Review could not be submitted.");
+        return Err(format!(
+            "Review on pull request #{} was rejected: {}",
+            number, message
+        ));
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub review submit failed: {}", body));
+    }
+
+    response
+        .json::<GithubReview>()
+        .await
+        .map_err(|e| e.to_string())
+}
+
 pub async fn github_create_repo(
     app: AppHandle,
     client: &Client,
@@ -656,8 +1663,9 @@ pub async fn github_create_repo(
     }
 
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     let response = client
-        .post(format!("{}/user/repos", API_BASE))
+        .post(format!("{}/user/repos", api_base))
         .headers(build_api_headers(&token))
         .json(&json!({
             "name": name.trim(),
@@ -667,6 +1675,7 @@ pub async fn github_create_repo(
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if response.status() == StatusCode::UNAUTHORIZED {
         return Err("GitHub token is invalid or expired.".to_string());
@@ -683,6 +1692,90 @@ pub async fn github_create_repo(
         .map_err(|e| e.to_string())
 }
 
+/// Forks `owner/repo` into the user's account (or `organization`) and waits
+/// for GitHub to finish copying it so an immediate clone doesn't race. If the
+/// fork isn't readable before the timeout this fails; forking again is safe
+/// since GitHub returns the existing fork.
+#[tauri::command]
+pub async fn github_fork_repo(
+    app: AppHandle,
+    client: State<'_, Client>,
+    owner: String,
+    repo: String,
+    organization: Option<String>,
+) -> Result<GithubRepo, String> {
+    let owner = owner.trim();
+    let repo = repo.trim();
+    if owner.is_empty() || repo.is_empty() {
+        return Err("GitHub repository is required.".to_string());
+    }
+    let organization = organization
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
+    let body = match &organization {
+        Some(organization) => json!({ "organization": organization }),
+        None => json!({}),
+    };
+    let response = client
+        .post(format!("{}/repos/{}/{}/forks", api_base, owner, repo))
+        .headers(build_api_headers(&token))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        return Err("GitHub token is invalid or expired.".to_string());
+    }
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(format!(
+            "GitHub repository {}/{} was not found.",
+            owner, repo
+        ));
+    }
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("GitHub fork failed: {}", body));
+    }
+
+    let fork = response
+        .json::<GithubRepo>()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let deadline = Instant::now() + FORK_READY_TIMEOUT;
+    loop {
+        let response = client
+            .get(format!(
+                "{}/repos/{}/commits?per_page=1",
+                api_base, fork.full_name
+            ))
+            .headers(build_api_headers(&token))
+            .send()
+            .await;
+        if let Ok(response) = response {
+            let response = check_rate_limit(response)?;
+            // An empty repository answers 409 Conflict, so the fork exists.
+            if response.status().is_success() || response.status() == StatusCode::CONFLICT {
+                return Ok(fork);
+            }
+        }
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "GitHub is still creating the fork {}. Try again in a moment.",
+                fork.full_name
+            ));
+        }
+        sleep(FORK_READY_POLL_INTERVAL).await;
+    }
+}
+
 #[tauri::command]
 pub async fn github_add_ssh_key(
     app: AppHandle,
@@ -691,6 +1784,7 @@ pub async fn github_add_ssh_key(
     key: String,
 ) -> Result<(), String> {
     let token = load_token(&app)?;
+    let api_base = api_base_url(&app)?;
     if title.trim().is_empty() {
         return Err("Key title is required.".to_string());
     }
@@ -699,12 +1793,13 @@ pub async fn github_add_ssh_key(
     }
 
     let response = client
-        .post(format!("{}/user/keys", API_BASE))
+        .post(format!("{}/user/keys", api_base))
         .headers(build_api_headers(&token))
         .json(&json!({ "title": title.trim(), "key": key.trim() }))
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    let response = check_rate_limit(response)?;
 
     if response.status().is_success() {
         return Ok(());
@@ -725,3 +1820,67 @@ pub async fn github_add_ssh_key(
     let body = response.text().await.unwrap_or_default();
     Err(format!("GitHub SSH key upload failed: {}", body))
 }
+
+#[tauri::command]
+pub async fn get_github_base_url(app: AppHandle) -> Result<GithubBaseUrls, String> {
+    Ok(GithubBaseUrls {
+        api_base_url: api_base_url(&app)?,
+        oauth_base_url: oauth_base_url(&app)?,
+    })
+}
+
+#[tauri::command]
+pub async fn set_github_base_url(
+    app: AppHandle,
+    api_base_url: String,
+    oauth_base_url: Option<String>,
+) -> Result<(), String> {
+    let oauth_base_url = oauth_base_url
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| derive_oauth_base_url(&api_base_url));
+    storage::set_github_base_urls(&app, &api_base_url, &oauth_base_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn granted(scopes: &[&str]) -> Vec<String> {
+        scopes.iter().map(|scope| scope.to_string()).collect()
+    }
+
+    #[test]
+    fn missing_scopes_checks_the_requested_scope() {
+        let token = granted(&["repo", "read:org"]);
+        assert!(missing_scopes("repo read:org", &token).is_empty());
+        assert_eq!(
+            missing_scopes(DEFAULT_SCOPE, &token),
+            vec!["write:public_key"]
+        );
+    }
+
+    #[test]
+    fn missing_scopes_reports_workflow_when_requested() {
+        let token = granted(&["repo", "write:public_key"]);
+        assert_eq!(
+            missing_scopes("repo write:public_key workflow", &token),
+            vec!["workflow"]
+        );
+    }
+
+    #[test]
+    fn missing_scopes_accepts_broader_grants() {
+        let token = granted(&["repo", "admin:public_key", "admin:org", "user"]);
+        assert!(
+            missing_scopes("public_repo write:public_key read:org user:email", &token).is_empty()
+        );
+    }
+
+    #[test]
+    fn encode_ref_path_escapes_reserved_characters() {
+        assert_eq!(encode_ref_path("feature/login"), "feature/login");
+        assert_eq!(encode_ref_path("fix#12"), "fix%2312");
+        assert_eq!(encode_ref_path("100%done"), "100%25done");
+    }
+}
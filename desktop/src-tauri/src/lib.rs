@@ -3,6 +3,7 @@ mod git;
 mod github;
 mod containers;
 mod backend;
+mod lima;
 mod opencode;
 mod project;
 mod ssh;
@@ -10,13 +11,26 @@ mod storage;
 mod blocking;
 
 use git::{
-    checkout_branch, clone_repository, create_branch, create_commit, current_branch, delete_branch,
-    discard_changes as discard_git_changes, get_commit_history, get_project_history,
-    get_remote_url as get_git_remote_url, get_repository_info, list_remotes, pull_from_remote,
-    push_to_remote, reset_to_commit as reset_git_to_commit, stage_file, unstage_file,
+    abort_merge as abort_git_merge, blame_file as blame_git_file, checkout_branch, checkout_commit as checkout_git_commit, clone_repository, create_branch, create_commit, current_branch, delete_branch,
+    delete_remote_branch as delete_git_remote_branch,
+    discard_changes as discard_git_changes, discard_file as discard_git_file, get_branch_divergence as get_git_branch_divergence,
+    get_commit_history, get_current_branch as get_git_current_branch, get_file_at_commit as get_git_file_at_commit,
+    get_file_history as get_git_file_history, get_ignored_files as get_git_ignored_files,
+    get_project_history, is_path_ignored as is_git_path_ignored,
+    add_or_update_remote, get_remote_url as get_git_remote_url, get_repos_status as get_git_repos_status,
+    get_repository_info_cached,
+    list_conflicts as list_git_conflicts, list_remotes,
+    merge_branch as merge_git_branch, pull_from_remote, rebase_abort as rebase_git_abort, rebase_continue as rebase_git_continue,
+    rebase_onto as rebase_git_onto, remove_remote as remove_git_remote,
+    resolve_conflict as resolve_git_conflict,
+    push_to_remote, rename_branch as rename_git_branch, reset_to_commit as reset_git_to_commit, revert_commit as revert_git_commit,
+    search_commits as search_git_commits, stage_file, unstage_all as unstage_all_files, unstage_file,
+    update_submodules as update_git_submodules, add_worktree as add_git_worktree,
+    list_worktrees as list_git_worktrees, remove_worktree as remove_git_worktree,
 };
 use opencode::{
-    check_command_exists, check_opencode_installed, install_opencode, opencode_send, OpencodeState,
+    check_command_exists, check_opencode_installed, install_opencode, opencode_cancel,
+    opencode_list_models, opencode_send, OpencodeState,
 };
 use reqwest::Client;
 use storage::{
@@ -32,21 +46,66 @@ use blocking::run_blocking;
 // ============================================================================
 
 #[tauri::command]
-async fn clone_repo(url: String, path: String, ssh_key_path: Option<String>) -> Result<String, String> {
+async fn clone_repo(
+    app: AppHandle,
+    url: String,
+    path: String,
+    ssh_key_path: Option<String>,
+    branch: Option<String>,
+    init_submodules: Option<bool>,
+    protocol: Option<String>,
+) -> Result<String, String> {
     run_blocking(move || {
-        let ssh_key_path =
-            ssh_key_path.ok_or_else(|| "SSH key is required to clone repositories.".to_string())?;
+        let auth = match protocol.as_deref().unwrap_or("ssh") {
+            "ssh" => git::GitAuth::Ssh(ssh_key_path.ok_or_else(|| {
+                "SSH key is required to clone repositories.".to_string()
+            })?),
+            "https" => git::GitAuth::Token(github::clone_token_for_url(&app, &url)?),
+            other => return Err(format!("Unsupported clone protocol '{}'", other)),
+        };
         if let Some(parent) = std::path::Path::new(&path).parent() {
             std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
         }
-        clone_repository(&url, &path, &ssh_key_path).map_err(|e| e.to_string())?;
+        clone_repository(
+            &url,
+            &path,
+            &auth,
+            branch.as_deref().filter(|branch| !branch.trim().is_empty()),
+            init_submodules.unwrap_or(false),
+            storage::get_git_network_timeout(&app)?,
+            Some(&app),
+        )
+        .map_err(|e| e.to_string())?;
         Ok("Repository cloned successfully".to_string())
     }).await
 }
 
 #[tauri::command]
-async fn get_repo_info(path: String) -> Result<git::RepositoryInfo, String> {
-    run_blocking(move || get_repository_info(&path).map_err(|e| e.to_string())).await
+async fn get_repo_info(path: String, refresh: Option<bool>) -> Result<git::RepositoryInfo, String> {
+    run_blocking(move || {
+        get_repository_info_cached(&path, refresh.unwrap_or(false)).map_err(|e| e.to_string())
+    })
+    .await
+}
+
+#[tauri::command]
+async fn get_current_branch(path: String) -> Result<git::CurrentBranchInfo, String> {
+    run_blocking(move || get_git_current_branch(&path).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn get_repos_status(paths: Vec<String>) -> Result<Vec<git::RepoStatusSummary>, String> {
+    run_blocking(move || Ok(get_git_repos_status(&paths))).await
+}
+
+#[tauri::command]
+async fn get_ignored_files(path: String) -> Result<Vec<String>, String> {
+    run_blocking(move || get_git_ignored_files(&path).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn is_path_ignored(path: String, file: String) -> Result<bool, String> {
+    run_blocking(move || is_git_path_ignored(&path, &file).map_err(|e| e.to_string())).await
 }
 
 #[tauri::command]
@@ -63,6 +122,44 @@ async fn get_project_commits(
     run_blocking(move || get_project_history(&path, &base_branch, count).map_err(|e| e.to_string())).await
 }
 
+#[tauri::command]
+async fn search_commits(
+    path: String,
+    query: Option<String>,
+    author: Option<String>,
+    max_count: usize,
+) -> Result<Vec<git::CommitInfo>, String> {
+    run_blocking(move || {
+        search_git_commits(&path, query.as_deref(), author.as_deref(), max_count)
+            .map_err(|e| e.to_string())
+    }).await
+}
+
+#[tauri::command]
+async fn get_branch_divergence(path: String, base_branch: String) -> Result<git::Divergence, String> {
+    run_blocking(move || get_git_branch_divergence(&path, &base_branch).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn get_file_history(
+    path: String,
+    file: String,
+    max_count: usize,
+) -> Result<Vec<git::CommitInfo>, String> {
+    run_blocking(move || get_git_file_history(&path, &file, max_count).map_err(|e| e.to_string()))
+        .await
+}
+
+#[tauri::command]
+async fn blame_file(path: String, file: String) -> Result<git::BlameResult, String> {
+    run_blocking(move || blame_git_file(&path, &file).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn get_file_at_commit(path: String, commit_id: String, file: String) -> Result<String, String> {
+    run_blocking(move || get_git_file_at_commit(&path, &commit_id, &file).map_err(|e| e.to_string())).await
+}
+
 #[tauri::command]
 async fn stage(path: String, file: String) -> Result<String, String> {
     run_blocking(move || {
@@ -80,8 +177,35 @@ async fn unstage(path: String, file: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn commit(path: String, message: String, author: String, email: String) -> Result<String, String> {
-    run_blocking(move || create_commit(&path, &message, &author, &email).map_err(|e| e.to_string())).await
+async fn unstage_all(path: String) -> Result<String, String> {
+    run_blocking(move || {
+        unstage_all_files(&path).map_err(|e| e.to_string())?;
+        Ok("All files unstaged".to_string())
+    }).await
+}
+
+#[tauri::command]
+async fn commit(
+    path: String,
+    message: String,
+    author: String,
+    email: String,
+    sign: Option<bool>,
+    trailers: Option<Vec<(String, String)>>,
+    sign_off: Option<bool>,
+) -> Result<String, String> {
+    run_blocking(move || {
+        create_commit(
+            &path,
+            &message,
+            &author,
+            &email,
+            sign.unwrap_or(false),
+            &trailers.unwrap_or_default(),
+            sign_off.unwrap_or(false),
+        )
+        .map_err(|e| e.to_string())
+    }).await
 }
 
 #[tauri::command]
@@ -93,13 +217,73 @@ async fn reset_to_commit(path: String, commit_id: String) -> Result<String, Stri
 }
 
 #[tauri::command]
-async fn discard_changes(path: String) -> Result<String, String> {
+async fn revert_commit(path: String, commit_id: String) -> Result<git::RevertResult, String> {
+    run_blocking(move || revert_git_commit(&path, &commit_id).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn merge_branch(path: String, branch: String) -> Result<git::MergeResult, String> {
+    run_blocking(move || merge_git_branch(&path, &branch).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn list_conflicts(path: String) -> Result<Vec<git::ConflictEntry>, String> {
+    run_blocking(move || list_git_conflicts(&path).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn resolve_conflict(path: String, file: String) -> Result<String, String> {
+    run_blocking(move || {
+        resolve_git_conflict(&path, &file).map_err(|e| e.to_string())?;
+        Ok("Conflict marked as resolved".to_string())
+    })
+    .await
+}
+
+#[tauri::command]
+async fn abort_merge(path: String) -> Result<String, String> {
     run_blocking(move || {
-        discard_git_changes(&path).map_err(|e| e.to_string())?;
+        abort_git_merge(&path).map_err(|e| e.to_string())?;
+        Ok("Merge aborted".to_string())
+    })
+    .await
+}
+
+#[tauri::command]
+async fn rebase_onto(path: String, base_branch: String) -> Result<git::RebaseResult, String> {
+    run_blocking(move || rebase_git_onto(&path, &base_branch).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn rebase_continue(path: String) -> Result<git::RebaseResult, String> {
+    run_blocking(move || rebase_git_continue(&path).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn rebase_abort(path: String) -> Result<String, String> {
+    run_blocking(move || {
+        rebase_git_abort(&path).map_err(|e| e.to_string())?;
+        Ok("Rebase aborted".to_string())
+    })
+    .await
+}
+
+#[tauri::command]
+async fn discard_changes(path: String, remove_untracked: Option<bool>) -> Result<String, String> {
+    run_blocking(move || {
+        discard_git_changes(&path, remove_untracked.unwrap_or(false)).map_err(|e| e.to_string())?;
         Ok("Discarded changes".to_string())
     }).await
 }
 
+#[tauri::command]
+async fn discard_file(path: String, file: String) -> Result<String, String> {
+    run_blocking(move || {
+        discard_git_file(&path, &file).map_err(|e| e.to_string())?;
+        Ok("Discarded file changes".to_string())
+    }).await
+}
+
 #[tauri::command]
 async fn reset_app_state(app: AppHandle) -> Result<String, String> {
     let mut errors: Vec<String> = Vec::new();
@@ -143,7 +327,7 @@ async fn reset_app_state(app: AppHandle) -> Result<String, String> {
     }
 
     github::github_clear_token(app.clone()).await?;
-    falck::clear_all_secrets().await?;
+    falck::clear_all_secrets(None).await?;
 
     let app_handle = app.clone();
     run_blocking(move || storage::reset_storage(&app_handle)).await?;
@@ -172,6 +356,20 @@ async fn delete_current_branch(path: String, branch: String) -> Result<String, S
     }).await
 }
 
+#[tauri::command]
+async fn rename_branch(
+    path: String,
+    old_name: String,
+    new_name: String,
+    force: Option<bool>,
+) -> Result<String, String> {
+    run_blocking(move || {
+        rename_git_branch(&path, &old_name, &new_name, force.unwrap_or(false))
+            .map_err(|e| e.to_string())?;
+        Ok(format!("Branch '{}' renamed to '{}'", old_name, new_name))
+    }).await
+}
+
 #[tauri::command]
 async fn checkout(path: String, branch: String) -> Result<String, String> {
     run_blocking(move || {
@@ -180,34 +378,131 @@ async fn checkout(path: String, branch: String) -> Result<String, String> {
     }).await
 }
 
+#[tauri::command]
+async fn checkout_commit(path: String, commit_id: String) -> Result<String, String> {
+    run_blocking(move || {
+        checkout_git_commit(&path, &commit_id).map_err(|e| e.to_string())?;
+        Ok(format!("Checked out commit '{}'", commit_id))
+    }).await
+}
+
+/// Uses the given SSH key and remembers it for the repo, or falls back to the
+/// key last used with the repo.
+fn resolve_repo_ssh_key(app: &AppHandle, path: &str, ssh_key_path: Option<String>) -> Option<String> {
+    match ssh_key_path.filter(|key| !key.trim().is_empty()) {
+        Some(key) => {
+            let _ = storage::set_repo_ssh_key(app, path, Some(&key));
+            Some(key)
+        }
+        None => storage::get_repo_ssh_key(app, path).ok().flatten(),
+    }
+}
+
 #[tauri::command]
 async fn push(
+    app: AppHandle,
     path: String,
     remote: String,
     branch: String,
     ssh_key_path: Option<String>,
+    force: Option<git::ForceMode>,
+    remote_branch: Option<String>,
+    set_upstream: Option<bool>,
 ) -> Result<String, String> {
     run_blocking(move || {
-        let ssh_key_path = ssh_key_path.ok_or_else(|| "SSH key is required to push.".to_string())?;
-        push_to_remote(&path, &remote, &branch, &ssh_key_path).map_err(|e| e.to_string())?;
+        let ssh_key_path = resolve_repo_ssh_key(&app, &path, ssh_key_path)
+            .ok_or_else(|| "SSH key is required to push.".to_string())?;
+        let timeout = storage::get_git_network_timeout(&app)?;
+        let settings = git::PushSettings {
+            force: force.unwrap_or_default(),
+            remote_branch,
+            set_upstream: set_upstream.unwrap_or(false),
+        };
+        push_to_remote(&path, &remote, &branch, &ssh_key_path, &settings, timeout)
+            .map_err(|e| e.to_string())?;
         Ok("Pushed successfully".to_string())
     }).await
 }
 
+#[tauri::command]
+async fn delete_remote_branch(
+    app: AppHandle,
+    path: String,
+    remote: String,
+    branch: String,
+    ssh_key_path: Option<String>,
+) -> Result<String, String> {
+    run_blocking(move || {
+        let ssh_key_path = resolve_repo_ssh_key(&app, &path, ssh_key_path)
+            .ok_or_else(|| "SSH key is required to delete remote branches.".to_string())?;
+        let timeout = storage::get_git_network_timeout(&app)?;
+        delete_git_remote_branch(&path, &remote, &branch, &ssh_key_path, timeout)
+            .map_err(|e| e.to_string())?;
+        Ok(format!("Deleted {}/{}", remote, branch))
+    })
+    .await
+}
+
 #[tauri::command]
 async fn pull(
+    app: AppHandle,
     path: String,
     remote: String,
     branch: String,
     ssh_key_path: Option<String>,
 ) -> Result<String, String> {
     run_blocking(move || {
-        let ssh_key_path = ssh_key_path.ok_or_else(|| "SSH key is required to pull.".to_string())?;
-        pull_from_remote(&path, &remote, &branch, &ssh_key_path).map_err(|e| e.to_string())?;
+        let ssh_key_path = resolve_repo_ssh_key(&app, &path, ssh_key_path)
+            .ok_or_else(|| "SSH key is required to pull.".to_string())?;
+        let timeout = storage::get_git_network_timeout(&app)?;
+        pull_from_remote(&path, &remote, &branch, &ssh_key_path, timeout)
+            .map_err(|e| e.to_string())?;
         Ok("Pulled successfully".to_string())
     }).await
 }
 
+#[tauri::command]
+async fn update_submodules(
+    app: AppHandle,
+    path: String,
+    ssh_key_path: Option<String>,
+) -> Result<String, String> {
+    run_blocking(move || {
+        let ssh_key_path = resolve_repo_ssh_key(&app, &path, ssh_key_path)
+            .ok_or_else(|| "SSH key is required to fetch submodules.".to_string())?;
+        let count = update_git_submodules(&path, &ssh_key_path).map_err(|e| e.to_string())?;
+        Ok(format!("Updated {} submodule(s)", count))
+    }).await
+}
+
+#[tauri::command]
+async fn list_worktrees(path: String) -> Result<Vec<git::WorktreeInfo>, String> {
+    run_blocking(move || list_git_worktrees(&path).map_err(|e| e.to_string())).await
+}
+
+#[tauri::command]
+async fn add_worktree(
+    path: String,
+    name: String,
+    branch: String,
+    dest: String,
+) -> Result<git::WorktreeInfo, String> {
+    run_blocking(move || {
+        if let Some(parent) = std::path::Path::new(&dest).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        add_git_worktree(&path, &name, &branch, &dest).map_err(|e| e.to_string())
+    }).await
+}
+
+#[tauri::command]
+async fn remove_worktree(path: String, name: String) -> Result<String, String> {
+    run_blocking(move || {
+        remove_git_worktree(&path, &name).map_err(|e| e.to_string())?;
+        Ok(format!("Worktree '{}' removed", name))
+    }).await
+}
+
 #[tauri::command]
 async fn get_remotes(path: String) -> Result<Vec<String>, String> {
     run_blocking(move || list_remotes(&path).map_err(|e| e.to_string())).await
@@ -219,16 +514,69 @@ async fn get_remote_url(path: String, remote: String) -> Result<String, String>
 }
 
 #[tauri::command]
-async fn save_repo_entry(app: tauri::AppHandle, name: String, path: String) -> Result<(), String> {
+async fn set_remote_url(path: String, remote: String, url: String) -> Result<String, String> {
+    run_blocking(move || {
+        let url = url.trim();
+        if url.is_empty() {
+            return Err("Remote URL cannot be empty.".to_string());
+        }
+        add_or_update_remote(&path, &remote, url).map_err(|e| e.to_string())?;
+        Ok(format!("Remote '{}' now points to {}", remote, url))
+    }).await
+}
+
+#[tauri::command]
+async fn remove_remote(path: String, remote: String) -> Result<String, String> {
+    run_blocking(move || {
+        remove_git_remote(&path, &remote).map_err(|e| e.to_string())?;
+        Ok(format!("Remote '{}' removed", remote))
+    }).await
+}
+
+#[tauri::command]
+async fn save_repo_entry(
+    app: tauri::AppHandle,
+    name: String,
+    path: String,
+    ssh_key_path: Option<String>,
+) -> Result<(), String> {
     run_blocking(move || {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map_err(|e| e.to_string())?
             .as_secs() as i64;
-        save_repo(&app, &name, &path, now)
+        save_repo(&app, &name, &path, now, ssh_key_path.as_deref())
     }).await
 }
 
+#[tauri::command]
+async fn prune_missing_repos(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    run_blocking(move || storage::prune_missing_repos(&app)).await
+}
+
+#[tauri::command]
+async fn set_repo_tags(
+    app: tauri::AppHandle,
+    path: String,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    run_blocking(move || storage::set_repo_tags(&app, &path, &tags)).await
+}
+
+#[tauri::command]
+async fn list_repos_by_tag(app: tauri::AppHandle, tag: String) -> Result<Vec<SavedRepo>, String> {
+    run_blocking(move || storage::list_repos_by_tag(&app, &tag)).await
+}
+
+#[tauri::command]
+async fn set_repo_ssh_key(
+    app: tauri::AppHandle,
+    path: String,
+    key_path: Option<String>,
+) -> Result<(), String> {
+    run_blocking(move || storage::set_repo_ssh_key(&app, &path, key_path.as_deref())).await
+}
+
 #[tauri::command]
 async fn list_repo_entries(app: tauri::AppHandle) -> Result<Vec<SavedRepo>, String> {
     run_blocking(move || list_repos(&app)).await
@@ -249,6 +597,20 @@ async fn set_default_repo_directory(app: tauri::AppHandle, path: String) -> Resu
     run_blocking(move || set_default_repo_dir(&app, &path)).await
 }
 
+#[tauri::command]
+async fn get_git_network_timeout(app: tauri::AppHandle) -> Result<u64, String> {
+    run_blocking(move || storage::get_git_network_timeout(&app).map(|timeout| timeout.as_secs()))
+        .await
+}
+
+#[tauri::command]
+async fn set_git_network_timeout(
+    app: tauri::AppHandle,
+    seconds: Option<u32>,
+) -> Result<(), String> {
+    run_blocking(move || storage::set_git_network_timeout(&app, seconds)).await
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let app = tauri::Builder::default()
@@ -257,67 +619,136 @@ pub fn run() {
         .manage(Client::new())
         .manage(OpencodeState::default())
         .manage(falck::FalckProcessState::default())
+        .setup(|app| {
+            if let Err(err) = falck::init_secrets_vault(app.handle()) {
+                eprintln!("[falck][secrets] vault unavailable, using memory only: {}", err);
+            }
+            falck::start_liveness_watcher(app.handle().clone());
+            Ok(())
+        })
         .on_window_event(|window, event| {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 let state = window.app_handle().state::<falck::FalckProcessState>();
                 falck::stop_all_running_apps(&state);
-                backend::stop_all_repo_backends(&window.app_handle());
+                backend::stop_repo_backends_on_exit(window.app_handle());
             }
         })
         .invoke_handler(tauri::generate_handler![
             clone_repo,
             get_repo_info,
+            get_current_branch,
+            get_repos_status,
+            get_ignored_files,
+            is_path_ignored,
             get_commits,
             get_project_commits,
+            search_commits,
+            get_file_history,
+            blame_file,
+            get_branch_divergence,
+            get_file_at_commit,
             stage,
             unstage,
+            unstage_all,
             commit,
             reset_to_commit,
+            revert_commit,
+            merge_branch,
+            abort_merge,
+            list_conflicts,
+            resolve_conflict,
+            rebase_onto,
+            rebase_continue,
+            rebase_abort,
             discard_changes,
+            discard_file,
             create_new_branch,
             delete_current_branch,
+            rename_branch,
             checkout,
+            checkout_commit,
             push,
+            delete_remote_branch,
             pull,
+            update_submodules,
+            list_worktrees,
+            add_worktree,
+            remove_worktree,
             get_remotes,
             get_remote_url,
+            set_remote_url,
+            remove_remote,
             save_repo_entry,
+            set_repo_ssh_key,
+            set_repo_tags,
+            prune_missing_repos,
+            list_repos_by_tag,
             list_repo_entries,
             remove_repo_entry,
             get_default_repo_directory,
             set_default_repo_directory,
+            get_git_network_timeout,
+            set_git_network_timeout,
             opencode_send,
+            opencode_cancel,
+            opencode_list_models,
             check_opencode_installed,
             install_opencode,
             check_command_exists,
             ssh::generate_new_ssh_key,
             ssh::list_ssh_keys,
             ssh::add_ssh_key_to_agent,
+            ssh::remove_ssh_key_from_agent,
             ssh::test_ssh_github,
+            ssh::test_ssh_host,
             ssh::get_current_os,
             github::github_start_device_flow,
             github::github_poll_device_token,
             github::github_has_token,
+            github::github_check_token_scopes,
+            github::github_verify_token_scopes,
             github::github_clear_token,
             github::github_get_user,
             github::github_list_repos,
             github::github_list_repo_collaborators,
+            github::github_list_branches,
+            github::github_repo_clone_url,
+            github::github_pr_defaults,
+            github::github_list_pull_requests,
             github::github_request_reviewers,
             github::github_create_pull_request,
+            github::github_merge_pull_request,
+            github::github_mark_pr_ready,
+            github::github_submit_review,
+            github::github_get_pr_checks,
             github::github_add_ssh_key,
+            github::github_fork_repo,
+            github::get_github_base_url,
+            github::set_github_base_url,
             project::create_astro_project,
+            project::create_project,
             falck::load_falck_config,
+            falck::validate_falck_config,
+            falck::scaffold_falck_config,
+            falck::prune_vm_port_forwards,
             falck::check_falck_prerequisites,
+            falck::check_all_prerequisites,
             falck::run_falck_prerequisite_install,
             falck::get_app_secrets_for_config,
             falck::set_app_secret,
             falck::check_secrets_satisfied,
             falck::check_falck_setup,
             falck::run_falck_setup,
+            falck::run_all_falck_setup,
             falck::launch_falck_app,
+            falck::launch_all_falck_apps,
+            falck::launch_falck_group,
             falck::run_falck_cleanup,
             falck::upload_falck_assets,
             falck::kill_falck_app,
+            falck::list_running_apps,
+            falck::get_falck_app_logs,
+            falck::restart_falck_app,
             falck::check_port_available,
             falck::open_browser_to_url,
             falck::clear_all_secrets,
@@ -329,11 +760,23 @@ pub fn run() {
             containers::delete_container,
             backend::get_backend_mode,
             backend::set_backend_mode,
+            backend::get_supported_backend_modes,
             backend::check_virtualized_backend_prereq,
             backend::ensure_repo_backend,
+            backend::run_backend_command,
+            backend::cancel_backend_operation,
+            backend::get_backend_stats,
+            backend::get_backend_capabilities,
+            backend::get_vm_logs,
+            backend::stop_all_backends,
+            backend::get_stop_vm_on_exit,
+            backend::set_stop_vm_on_exit,
             backend::stop_repo_backend,
             backend::delete_repo_backend,
+            backend::reset_repo_backend,
             backend::list_backend_vms,
+            backend::get_vm_resources,
+            backend::set_vm_resources,
             backend::stop_backend_vm,
             backend::delete_backend_vm,
         ])
@@ -350,7 +793,7 @@ pub fn run() {
             api.prevent_exit();
             let state = app_handle.state::<falck::FalckProcessState>();
             falck::stop_all_running_apps(&state);
-            backend::stop_all_repo_backends(app_handle);
+            backend::stop_repo_backends_on_exit(app_handle);
             app_handle.exit(0);
         } else if let tauri::RunEvent::Exit { .. } = event {
             if shutdown_guard.swap(true, Ordering::SeqCst) {
@@ -358,7 +801,7 @@ pub fn run() {
             }
             let state = app_handle.state::<falck::FalckProcessState>();
             falck::stop_all_running_apps(&state);
-            backend::stop_all_repo_backends(app_handle);
+            backend::stop_repo_backends_on_exit(app_handle);
         }
     });
 }
@@ -0,0 +1,183 @@
+//! Lima instance lifecycle shared by the virtualized backend and the
+//! container runtime. Both paths create, start and inspect instances through
+//! these helpers so a VM set up by one of them works for the other.
+
+use serde::Deserialize;
+use std::path::Path;
+use std::process::Command;
+
+/// Flags every Falck-managed instance is created with. The container runtime
+/// needs system containerd for nerdctl, and dev servers need writable repo
+/// mounts with inotify for file watching.
+const CREATE_FLAGS: &[&str] = &[
+    "--tty=false",
+    "--containerd=system",
+    "--mount-writable",
+    "--mount-inotify",
+];
+const START_FLAGS: &[&str] = &["--tty=false", "--mount-writable", "--mount-inotify"];
+/// Object keys older and newer `limactl list --json` versions wrap the
+/// instance array in.
+const LIST_WRAPPER_KEYS: &[&str] = &["entries", "items", "instances"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InstanceState {
+    Missing,
+    Stopped,
+    Running,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LimaListEntry {
+    #[serde(alias = "Name")]
+    pub name: String,
+    #[serde(alias = "Status")]
+    pub status: Option<String>,
+}
+
+pub(crate) fn limactl_command(limactl: Option<&Path>) -> Command {
+    if let Some(path) = limactl {
+        Command::new(path)
+    } else {
+        Command::new("limactl")
+    }
+}
+
+/// `limactl create` from `template` (Lima's default template when `None`),
+/// with extra `--set` expressions applied on top.
+pub(crate) fn create_command(
+    limactl: Option<&Path>,
+    name: &str,
+    template: Option<&Path>,
+    sets: &[String],
+) -> Command {
+    let mut cmd = limactl_command(limactl);
+    cmd.arg("create").args(CREATE_FLAGS).args(["--name", name]);
+    for expr in sets {
+        cmd.args(["--set", expr]);
+    }
+    if let Some(template) = template {
+        cmd.arg(template);
+    } else {
+        cmd.arg("template:default");
+    }
+    cmd
+}
+
+pub(crate) fn start_command(limactl: Option<&Path>, name: &str, sets: &[String]) -> Command {
+    let mut cmd = limactl_command(limactl);
+    cmd.arg("start").args(START_FLAGS);
+    for expr in sets {
+        cmd.args(["--set", expr]);
+    }
+    cmd.arg(name);
+    cmd
+}
+
+pub(crate) fn instance_state(entries: &[LimaListEntry], name: &str) -> InstanceState {
+    match entries.iter().find(|entry| entry.name == name) {
+        None => InstanceState::Missing,
+        Some(entry) => {
+            let status = entry.status.as_deref().unwrap_or("").to_lowercase();
+            if status.contains("running") {
+                InstanceState::Running
+            } else {
+                InstanceState::Stopped
+            }
+        }
+    }
+}
+
+/// Malformed entries are skipped so one odd instance does not hide the rest.
+fn entries_from_array(items: &[serde_json::Value]) -> Vec<LimaListEntry> {
+    items
+        .iter()
+        .filter_map(|entry| LimaListEntry::deserialize(entry).ok())
+        .collect()
+}
+
+fn entries_from_value(value: serde_json::Value) -> Option<Vec<LimaListEntry>> {
+    match value {
+        serde_json::Value::Array(items) => Some(entries_from_array(&items)),
+        serde_json::Value::Object(obj) => {
+            for key in LIST_WRAPPER_KEYS {
+                if let Some(serde_json::Value::Array(items)) = obj.get(*key) {
+                    return Some(entries_from_array(items));
+                }
+            }
+            serde_json::from_value(serde_json::Value::Object(obj))
+                .ok()
+                .map(|entry| vec![entry])
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_list_entries(raw: &[u8]) -> Result<Vec<LimaListEntry>, String> {
+    if raw.iter().all(|byte| byte.is_ascii_whitespace()) {
+        return Ok(Vec::new());
+    }
+    let mut slices = vec![raw];
+    if let Some(start) = raw.iter().position(|b| *b == b'{' || *b == b'[') {
+        slices.push(&raw[start..]);
+    }
+
+    let mut last_error = None;
+    let mut entries = Vec::new();
+    let mut parsed_any = false;
+    for slice in slices {
+        let stream = serde_json::Deserializer::from_slice(slice).into_iter::<serde_json::Value>();
+        for result in stream {
+            match result {
+                Ok(value) => {
+                    parsed_any = true;
+                    if let Some(mut next) = entries_from_value(value) {
+                        entries.append(&mut next);
+                    }
+                }
+                Err(err) => {
+                    last_error = Some(format!("Failed to parse Lima VM list: {err}"));
+                    break;
+                }
+            }
+        }
+        if !entries.is_empty() {
+            return Ok(entries);
+        }
+    }
+    if entries.is_empty() && parsed_any && last_error.is_none() {
+        return Ok(Vec::new());
+    }
+    Err(last_error
+        .unwrap_or_else(|| "Failed to parse Lima VM list: unexpected JSON format.".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_list_entries_reads_json_lines() {
+        let raw = br#"{"name":"falck-a","status":"Running"}
+{"name":"falck-b","status":"Stopped"}"#;
+        let entries = parse_list_entries(raw).unwrap();
+        assert_eq!(instance_state(&entries, "falck-a"), InstanceState::Running);
+        assert_eq!(instance_state(&entries, "falck-b"), InstanceState::Stopped);
+        assert_eq!(instance_state(&entries, "falck-c"), InstanceState::Missing);
+    }
+
+    #[test]
+    fn parse_list_entries_accepts_wrappers_and_capitalised_keys() {
+        let raw = br#"{"instances":[{"Name":"falck-a","Status":"Running"}]}"#;
+        let entries = parse_list_entries(raw).unwrap();
+        assert_eq!(instance_state(&entries, "falck-a"), InstanceState::Running);
+    }
+
+    #[test]
+    fn parse_list_entries_skips_malformed_entries() {
+        let raw = br#"[{"status":"Running"},{"name":"falck-a","status":"Running"}]"#;
+        let entries = parse_list_entries(raw).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "falck-a");
+    }
+}
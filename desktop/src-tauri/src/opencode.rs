@@ -1,30 +1,48 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::env;
 use std::ffi::OsStr;
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Manager, Runtime, State};
 
 use crate::blocking::run_blocking;
 use crate::falck::load_shell_env;
 
-pub struct OpencodeState(pub Mutex<Option<SidecarProcess>>);
+const CANCELED_MESSAGE: &str = "OpenCode request canceled";
+
+pub struct OpencodeState {
+    sidecar: Mutex<Option<SidecarProcess>>,
+    requests: Mutex<HashMap<String, InFlightRequest>>,
+}
 
 impl Default for OpencodeState {
     fn default() -> Self {
-        Self(Mutex::new(None))
+        Self {
+            sidecar: Mutex::new(None),
+            requests: Mutex::new(HashMap::new()),
+        }
     }
 }
 
 pub struct SidecarProcess {
-    _child: Child,
+    child: Arc<Mutex<Child>>,
     stdin: ChildStdin,
     stdout: BufReader<ChildStdout>,
 }
 
+/// A request waiting on the sidecar. The sidecar answers one request at a
+/// time, so canceling means killing the sidecar and letting the next send
+/// respawn it.
+struct InFlightRequest {
+    child: Arc<Mutex<Child>>,
+    canceled: Arc<AtomicBool>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OpenCodeStatus {
     pub installed: bool,
@@ -89,11 +107,14 @@ pub async fn opencode_send(
     _state: State<'_, OpencodeState>,
     cmd: String,
     args: Value,
+    model: Option<String>,
+    provider: Option<String>,
+    request_id: Option<String>,
 ) -> Result<Value, String> {
     run_blocking(move || {
         let state = app.state::<OpencodeState>();
         let mut guard = state
-            .0
+            .sidecar
             .lock()
             .map_err(|_| "Sidecar lock poisoned".to_string())?;
         if guard.is_none() {
@@ -101,25 +122,36 @@ pub async fn opencode_send(
         }
 
         let process = guard.as_mut().ok_or("Sidecar not available")?;
-        let request = build_request(cmd, args)?;
-
-        process
-            .stdin
-            .write_all(request.as_bytes())
-            .map_err(|e| e.to_string())?;
-        process.stdin.write_all(b"\n").map_err(|e| e.to_string())?;
-        process.stdin.flush().map_err(|e| e.to_string())?;
-
-        let mut response_line = String::new();
-        let bytes_read = process
-            .stdout
-            .read_line(&mut response_line)
-            .map_err(|e| e.to_string())?;
-
-        if bytes_read == 0 {
+        let model = selected_model(model.as_deref(), provider.as_deref());
+        let request = build_request(cmd, args, model)?;
+
+        let canceled = Arc::new(AtomicBool::new(false));
+        if let Some(id) = &request_id {
+            if let Ok(mut requests) = state.requests.lock() {
+                requests.insert(
+                    id.clone(),
+                    InFlightRequest {
+                        child: process.child.clone(),
+                        canceled: canceled.clone(),
+                    },
+                );
+            }
+        }
+        let response_line = exchange(process, &request);
+        if let Some(id) = &request_id {
+            if let Ok(mut requests) = state.requests.lock() {
+                requests.remove(id);
+            }
+        }
+
+        if canceled.load(Ordering::SeqCst) {
             *guard = None;
-            return Err("OpenCode sidecar exited unexpectedly".to_string());
+            return Err(CANCELED_MESSAGE.to_string());
         }
+        let Some(response_line) = response_line? else {
+            *guard = None;
+            return Err("OpenCode sidecar exited unexpectedly".to_string());
+        };
 
         let response: Value =
             serde_json::from_str(response_line.trim()).map_err(|e| e.to_string())?;
@@ -137,7 +169,117 @@ pub async fn opencode_send(
     .await
 }
 
-fn build_request(cmd: String, args: Value) -> Result<String, String> {
+/// Cancels an in-flight `opencode_send` by killing the sidecar it is waiting
+/// on. Returns false when no request with that id is running.
+#[tauri::command]
+pub async fn opencode_cancel(app: AppHandle, request_id: String) -> Result<bool, String> {
+    run_blocking(move || {
+        let state = app.state::<OpencodeState>();
+        let request = state
+            .requests
+            .lock()
+            .map_err(|_| "Request lock poisoned".to_string())?
+            .remove(&request_id);
+        let Some(request) = request else {
+            return Ok(false);
+        };
+        request.canceled.store(true, Ordering::SeqCst);
+        let mut child = request
+            .child
+            .lock()
+            .map_err(|_| "Sidecar lock poisoned".to_string())?;
+        kill_sidecar(&mut child);
+        Ok(true)
+    })
+    .await
+}
+
+/// Writes one request line and reads one response line. Returns `None` when
+/// the sidecar closed its stdout.
+fn exchange(process: &mut SidecarProcess, request: &str) -> Result<Option<String>, String> {
+    process
+        .stdin
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+    process.stdin.write_all(b"\n").map_err(|e| e.to_string())?;
+    process.stdin.flush().map_err(|e| e.to_string())?;
+
+    let mut response_line = String::new();
+    let bytes_read = process
+        .stdout
+        .read_line(&mut response_line)
+        .map_err(|e| e.to_string())?;
+    if bytes_read == 0 {
+        return Ok(None);
+    }
+    Ok(Some(response_line))
+}
+
+/// Kills the sidecar together with anything it spawned (it runs in its own
+/// process group on Unix) and reaps it.
+fn kill_sidecar(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill")
+            .args(["-KILL", &format!("-{}", child.id())])
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(unix)]
+fn isolate_process_group(cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    cmd.process_group(0);
+}
+
+#[cfg(not(unix))]
+fn isolate_process_group(_cmd: &mut Command) {}
+
+/// Lists models as `provider/model` ids using the bundled opencode CLI.
+#[tauri::command]
+pub async fn opencode_list_models(
+    app: AppHandle,
+    provider: Option<String>,
+) -> Result<Vec<String>, String> {
+    run_blocking(move || {
+        let cli = find_opencode_cli(&app).ok_or("OpenCode CLI not found")?;
+        let mut cmd = Command::new(&cli);
+        apply_shell_env(&mut cmd);
+        cmd.arg("models");
+        if let Some(provider) = provider.as_deref().filter(|value| !value.is_empty()) {
+            cmd.arg(provider);
+        }
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to run opencode models: {}", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("opencode models failed: {}", stderr.trim()));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.contains('/') && !line.contains(char::is_whitespace))
+            .map(str::to_string)
+            .collect())
+    })
+    .await
+}
+
+/// The sidecar expects `provider/model`; a bare model name is qualified with
+/// the provider when one is given.
+fn selected_model(model: Option<&str>, provider: Option<&str>) -> Option<String> {
+    let model = model.map(str::trim).filter(|value| !value.is_empty())?;
+    match provider.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(provider) if !model.contains('/') => Some(format!("{}/{}", provider, model)),
+        _ => Some(model.to_string()),
+    }
+}
+
+fn build_request(cmd: String, args: Value, model: Option<String>) -> Result<String, String> {
   let mut map = Map::new();
     map.insert("cmd".to_string(), Value::String(cmd));
 
@@ -150,6 +292,9 @@ fn build_request(cmd: String, args: Value) -> Result<String, String> {
         }
         _ => return Err("args must be an object".to_string()),
     }
+    if let Some(model) = model {
+        map.insert("model".to_string(), Value::String(model));
+    }
 
   serde_json::to_string(&Value::Object(map)).map_err(|e| e.to_string())
 }
@@ -210,6 +355,7 @@ fn spawn_sidecar<R: Runtime>(app: &AppHandle<R>) -> Result<SidecarProcess, Strin
 fn spawn_process(path: PathBuf, cli_path: Option<&Path>) -> Result<SidecarProcess, String> {
     let mut cmd = Command::new(&path);
     apply_shell_env(&mut cmd);
+    isolate_process_group(&mut cmd);
     if let Some(cli_path) = cli_path {
         cmd.env("OPENCODE_CLI_PATH", cli_path);
     }
@@ -225,7 +371,7 @@ fn spawn_process(path: PathBuf, cli_path: Option<&Path>) -> Result<SidecarProces
     let stdout = child.stdout.take().ok_or("Failed to open sidecar stdout")?;
 
     Ok(SidecarProcess {
-        _child: child,
+        child: Arc::new(Mutex::new(child)),
         stdin,
         stdout: BufReader::new(stdout),
     })
@@ -234,6 +380,7 @@ fn spawn_process(path: PathBuf, cli_path: Option<&Path>) -> Result<SidecarProces
 fn spawn_bun(script: PathBuf, cli_path: Option<&Path>) -> Result<SidecarProcess, String> {
     let mut cmd = Command::new("bun");
     apply_shell_env(&mut cmd);
+    isolate_process_group(&mut cmd);
     if let Some(cli_path) = cli_path {
         cmd.env("OPENCODE_CLI_PATH", cli_path);
     }
@@ -250,7 +397,7 @@ fn spawn_bun(script: PathBuf, cli_path: Option<&Path>) -> Result<SidecarProcess,
     let stdout = child.stdout.take().ok_or("Failed to open sidecar stdout")?;
 
     Ok(SidecarProcess {
-        _child: child,
+        child: Arc::new(Mutex::new(child)),
         stdin,
         stdout: BufReader::new(stdout),
     })
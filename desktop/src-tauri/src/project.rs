@@ -1,5 +1,5 @@
 use crate::blocking::run_blocking;
-use crate::{git, github, opencode};
+use crate::{git, github, opencode, storage};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -8,6 +8,62 @@ use std::process::Command;
 use tauri::{AppHandle, Emitter, Manager, State};
 
 const ASTRO_TEMPLATE: &str = "MikkelWestermann/falck-astro";
+const VITE_TEMPLATE: &str = "react-ts";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProjectTemplate {
+    Astro,
+    Vite,
+    Next,
+    Node,
+}
+
+impl ProjectTemplate {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "astro" => Ok(ProjectTemplate::Astro),
+            "vite" => Ok(ProjectTemplate::Vite),
+            "next" | "nextjs" | "next.js" => Ok(ProjectTemplate::Next),
+            "node" => Ok(ProjectTemplate::Node),
+            other => Err(format!(
+                "Unknown project template '{}'. Expected astro, vite, next or node.",
+                other
+            )),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ProjectTemplate::Astro => "Astro",
+            ProjectTemplate::Vite => "Vite",
+            ProjectTemplate::Next => "Next.js",
+            ProjectTemplate::Node => "Node",
+        }
+    }
+
+    fn default_package_manager(self) -> &'static str {
+        match self {
+            ProjectTemplate::Astro => "bun",
+            _ => "npm",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectRequest {
+    pub template: String,
+    pub name: String,
+    pub path: String,
+    pub progress_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectResult {
+    pub path: String,
+    pub package_manager: String,
+}
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -62,6 +118,134 @@ struct AstroCreateOptions {
     astro_ref: Option<String>,
 }
 
+impl Default for AstroCreateOptions {
+    fn default() -> Self {
+        Self {
+            prompt_mode: "yes".to_string(),
+            install_dependencies: true,
+            initialize_git: false,
+            skip_houston: true,
+            integrations: None,
+            astro_ref: None,
+        }
+    }
+}
+
+/// Scaffolds a project from one of the known templates into `path`, which
+/// must not exist or be an empty directory.
+#[tauri::command]
+pub async fn create_project(
+    app: AppHandle,
+    input: CreateProjectRequest,
+) -> Result<CreateProjectResult, String> {
+    let template = ProjectTemplate::parse(&input.template)?;
+    let package_manager = scaffold_project(
+        &app,
+        template,
+        Path::new(&input.path),
+        input.name.trim(),
+        AstroCreateOptions::default(),
+        &input.progress_id,
+    )
+    .await?;
+    emit_progress(&app, &input.progress_id, "Finalizing project", None);
+    Ok(CreateProjectResult {
+        path: input.path,
+        package_manager,
+    })
+}
+
+/// Runs the template's create command into `local_path` and returns the
+/// project's package manager. `astro_options` only applies to Astro.
+async fn scaffold_project(
+    app: &AppHandle,
+    template: ProjectTemplate,
+    local_path: &Path,
+    name: &str,
+    astro_options: AstroCreateOptions,
+    progress_id: &Option<String>,
+) -> Result<String, String> {
+    emit_progress(
+        app,
+        progress_id,
+        "Preparing project folder",
+        Some(local_path.to_string_lossy().to_string()),
+    );
+    ensure_empty_target(local_path)?;
+    let parent_dir = local_path
+        .parent()
+        .ok_or_else(|| "Invalid project path.".to_string())?
+        .to_path_buf();
+    std::fs::create_dir_all(&parent_dir).map_err(|e| e.to_string())?;
+    let project_dir = local_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| "Project folder name is invalid.".to_string())?
+        .to_string();
+
+    let (program, args, cwd) = match template {
+        ProjectTemplate::Astro => {
+            emit_progress(app, progress_id, "Checking Bun installation", None);
+            let bun_path = {
+                let app = app.clone();
+                let progress_id = progress_id.clone();
+                run_blocking(move || ensure_bun_installed(&app, &progress_id)).await?
+            };
+            (
+                bun_path,
+                build_bun_create_args(&project_dir, &astro_options),
+                parent_dir,
+            )
+        }
+        ProjectTemplate::Vite => (
+            PathBuf::from(node_tool("npm")),
+            vec![
+                "create".to_string(),
+                "vite@latest".to_string(),
+                project_dir.clone(),
+                "--".to_string(),
+                "--template".to_string(),
+                VITE_TEMPLATE.to_string(),
+            ],
+            parent_dir,
+        ),
+        ProjectTemplate::Next => (
+            PathBuf::from(node_tool("npx")),
+            vec![
+                "--yes".to_string(),
+                "create-next-app@latest".to_string(),
+                project_dir.clone(),
+                "--yes".to_string(),
+                "--use-npm".to_string(),
+            ],
+            parent_dir,
+        ),
+        ProjectTemplate::Node => {
+            std::fs::create_dir_all(local_path).map_err(|e| e.to_string())?;
+            (
+                PathBuf::from(node_tool("npm")),
+                vec!["init".to_string(), "-y".to_string()],
+                local_path.to_path_buf(),
+            )
+        }
+    };
+
+    emit_progress(
+        app,
+        progress_id,
+        &format!("Scaffolding {} template", template.label()),
+        Some(format!("{} {}", program.display(), args.join(" "))),
+    );
+    run_blocking(move || run_create_command(&program, &cwd, &args)).await?;
+
+    if template == ProjectTemplate::Node && !name.is_empty() {
+        set_package_name(local_path, name)?;
+    }
+
+    Ok(detect_package_manager(local_path)
+        .unwrap_or_else(|| template.default_package_manager().to_string()))
+}
+
 #[tauri::command]
 pub async fn create_astro_project(
     app: AppHandle,
@@ -115,16 +299,7 @@ pub async fn create_astro_project(
         }
     }
 
-    emit_progress(
-        &app,
-        &progress_id,
-        "Preparing project folder",
-        Some(input.local_path.clone()),
-    );
     let local_path = PathBuf::from(&input.local_path);
-    if local_path.exists() {
-        return Err("Destination folder already exists.".to_string());
-    }
     if monorepo_enabled
         && local_path
             .components()
@@ -133,9 +308,6 @@ pub async fn create_astro_project(
         return Err("Project path cannot include .. segments.".to_string());
     }
 
-    let parent_dir = local_path
-        .parent()
-        .ok_or_else(|| "Invalid project path.".to_string())?;
     let monorepo_root_path = if monorepo_enabled {
         let root_path = PathBuf::from(&monorepo_root);
         if !root_path.exists() {
@@ -151,28 +323,16 @@ pub async fn create_astro_project(
     } else {
         None
     };
-    let parent_dir = parent_dir.to_path_buf();
-    std::fs::create_dir_all(&parent_dir).map_err(|e| e.to_string())?;
 
-    emit_progress(&app, &progress_id, "Checking Bun installation", None);
-    let bun_path = {
-        let app = app.clone();
-        let progress_id = progress_id.clone();
-        run_blocking(move || ensure_bun_installed(&app, &progress_id)).await?
-    };
-    let project_dir = local_path
-        .file_name()
-        .and_then(|name| name.to_str())
-        .ok_or_else(|| "Project folder name is invalid.".to_string())?;
-    let options = build_astro_options(&input);
-    let bun_args = build_bun_create_args(project_dir, &options);
-    emit_progress(
+    scaffold_project(
         &app,
+        ProjectTemplate::Astro,
+        &local_path,
+        input.project_name.trim(),
+        build_astro_options(&input),
         &progress_id,
-        "Scaffolding Astro template",
-        Some(format!("bun {}", bun_args.join(" "))),
-    );
-    run_blocking(move || run_bun_create(&bun_path, &parent_dir, &bun_args)).await?;
+    )
+    .await?;
 
     if monorepo_enabled {
         let root_path = monorepo_root_path
@@ -250,7 +410,7 @@ pub async fn create_astro_project(
         run_blocking({
             let local_path = input.local_path.clone();
             let message = message.clone();
-            move || git::create_commit(&local_path, &message, "", "").map_err(|e| e.to_string())
+            move || git::create_commit(&local_path, &message, "", "", false, &[], false).map_err(|e| e.to_string())
         })
         .await?;
     }
@@ -287,7 +447,19 @@ pub async fn create_astro_project(
         let local_path = input.local_path.clone();
         let branch = branch.clone();
         let ssh_key_path = input.ssh_key_path.clone();
-        move || git::push_to_remote(&local_path, "origin", &branch, &ssh_key_path).map_err(|e| e.to_string())
+        let app = app.clone();
+        move || {
+            let timeout = storage::get_git_network_timeout(&app)?;
+            git::push_to_remote(
+                &local_path,
+                "origin",
+                &branch,
+                &ssh_key_path,
+                &git::PushSettings::default(),
+                timeout,
+            )
+            .map_err(|e| e.to_string())
+        }
     })
     .await
     {
@@ -393,6 +565,9 @@ async fn merge_falck_config_with_opencode(
             "description": "Merge Falck config from Astro template into monorepo",
             "directory": directory,
         }),
+        None,
+        None,
+        None,
     )
     .await?;
 
@@ -451,6 +626,9 @@ Only touch Falck config files for this change and keep YAML formatting clean. Re
             "message": prompt,
             "directory": directory,
         }),
+        None,
+        None,
+        None,
     )
     .await?;
 
@@ -600,15 +778,70 @@ fn install_bun() -> Result<(), String> {
     }
 }
 
-fn run_bun_create(bun_path: &Path, parent_dir: &Path, args: &[String]) -> Result<(), String> {
-    let mut command = Command::new(bun_path);
-    command.current_dir(parent_dir);
+/// Scaffolding tools create the folder themselves, so an existing empty
+/// folder is removed first. Anything with content is rejected.
+fn ensure_empty_target(path: &Path) -> Result<(), String> {
+    if !path.exists() {
+        return Ok(());
+    }
+    if !path.is_dir() {
+        return Err("Destination exists and is not a folder.".to_string());
+    }
+    let mut entries = std::fs::read_dir(path).map_err(|e| e.to_string())?;
+    if entries.next().is_some() {
+        return Err("Destination folder already exists and is not empty.".to_string());
+    }
+    std::fs::remove_dir(path).map_err(|e| e.to_string())
+}
+
+fn node_tool(name: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{}.cmd", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn detect_package_manager(dir: &Path) -> Option<String> {
+    let lockfiles = [
+        ("bun.lock", "bun"),
+        ("bun.lockb", "bun"),
+        ("pnpm-lock.yaml", "pnpm"),
+        ("yarn.lock", "yarn"),
+        ("package-lock.json", "npm"),
+    ];
+    lockfiles
+        .iter()
+        .find(|(file, _)| dir.join(file).exists())
+        .map(|(_, manager)| manager.to_string())
+}
+
+fn set_package_name(dir: &Path, name: &str) -> Result<(), String> {
+    let package_path = dir.join("package.json");
+    let contents = std::fs::read_to_string(&package_path).map_err(|e| e.to_string())?;
+    let mut package: serde_json::Value =
+        serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+    if let Some(object) = package.as_object_mut() {
+        object.insert("name".to_string(), json!(name));
+    }
+    let updated = serde_json::to_string_pretty(&package).map_err(|e| e.to_string())?;
+    std::fs::write(&package_path, format!("{}\n", updated)).map_err(|e| e.to_string())
+}
+
+fn run_create_command(program: &Path, cwd: &Path, args: &[String]) -> Result<(), String> {
+    let tool = program
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("create")
+        .to_string();
+    let mut command = Command::new(program);
+    command.current_dir(cwd);
     for arg in args {
         command.arg(arg);
     }
     let output = command
         .output()
-        .map_err(|e| format!("Failed to run bun create: {}", e))?;
+        .map_err(|e| format!("Failed to run {}: {}", tool, e))?;
 
     if output.status.success() {
         return Ok(());
@@ -622,9 +855,9 @@ fn run_bun_create(bun_path: &Path, parent_dir: &Path, args: &[String]) -> Result
         .collect::<Vec<_>>()
         .join("\n");
     if details.is_empty() {
-        Err("Bun create failed.".to_string())
+        Err(format!("{} failed.", tool))
     } else {
-        Err(format!("Bun create failed: {}", details))
+        Err(format!("{} failed: {}", tool, details))
     }
 }
 
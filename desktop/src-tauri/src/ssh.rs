@@ -13,6 +13,7 @@ pub struct SSHKey {
     pub private_key_path: String,
     pub fingerprint: String,
     pub created_at: String,
+    pub loaded_in_agent: bool,
 }
 
 fn get_system_ssh_dir() -> Result<PathBuf, String> {
@@ -54,6 +55,12 @@ fn normalize_key_name(input: &str) -> Result<String, String> {
     Ok(sanitized)
 }
 
+fn public_key_path_for(private_key_path: &Path) -> PathBuf {
+    let mut path = private_key_path.as_os_str().to_owned();
+    path.push(".pub");
+    PathBuf::from(path)
+}
+
 fn get_key_fingerprint(public_key_path: &Path) -> Result<String, String> {
     let output = Command::new("ssh-keygen")
         .args(["-l", "-f"])
@@ -77,7 +84,7 @@ fn get_key_fingerprint(public_key_path: &Path) -> Result<String, String> {
 }
 
 fn read_ssh_key(private_key_path: &Path) -> Result<SSHKey, String> {
-    let public_key_path = private_key_path.with_extension("pub");
+    let public_key_path = public_key_path_for(private_key_path);
 
     let public_key = fs::read_to_string(&public_key_path)
         .map_err(|e| format!("Failed to read public key: {e}"))?
@@ -108,6 +115,7 @@ fn read_ssh_key(private_key_path: &Path) -> Result<SSHKey, String> {
         private_key_path: private_key_path.to_string_lossy().to_string(),
         fingerprint,
         created_at,
+        loaded_in_agent: false,
     })
 }
 
@@ -128,10 +136,14 @@ pub fn generate_ssh_key(
     passphrase: Option<&str>,
     key_type: &str,
 ) -> Result<SSHKey, String> {
+    let key_type = key_type.trim().to_lowercase();
+    if !matches!(key_type.as_str(), "ed25519" | "rsa") {
+        return Err("Key type must be ed25519 or rsa.".to_string());
+    }
     let sanitized_name = normalize_key_name(key_name)?;
     let ssh_dir = get_system_ssh_dir()?;
     let private_key_path = ssh_dir.join(format!("id_{}", sanitized_name));
-    let public_key_path = private_key_path.with_extension("pub");
+    let public_key_path = public_key_path_for(&private_key_path);
 
     if private_key_path.exists() || public_key_path.exists() {
         return Err("A key with that name already exists.".to_string());
@@ -166,6 +178,35 @@ pub fn generate_ssh_key(
     read_ssh_key(&private_key_path)
 }
 
+fn ensure_windows_agent_running() {
+    if cfg!(target_os = "windows") {
+        let _ = Command::new("powershell")
+            .args([
+                "-Command",
+                "Start-Service ssh-agent -ErrorAction SilentlyContinue",
+            ])
+            .output();
+    }
+}
+
+fn list_agent_fingerprints() -> Vec<String> {
+    let output = match Command::new("ssh-add").arg("-l").stdin(Stdio::null()).output() {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    // `ssh-add -l` exits non-zero when the agent has no identities or is unreachable.
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(|fingerprint| fingerprint.to_string())
+        .collect()
+}
+
 pub fn list_available_keys() -> Result<Vec<SSHKey>, String> {
     let ssh_dir = get_system_ssh_dir()?;
     let mut keys = Vec::new();
@@ -174,6 +215,8 @@ pub fn list_available_keys() -> Result<Vec<SSHKey>, String> {
         return Ok(keys);
     }
 
+    let agent_fingerprints = list_agent_fingerprints();
+
     for entry in fs::read_dir(&ssh_dir).map_err(|e| format!("Failed to read ~/.ssh: {e}"))? {
         let entry = entry.map_err(|e| format!("Failed to read ~/.ssh entry: {e}"))?;
         let path = entry.path();
@@ -191,12 +234,13 @@ pub fn list_available_keys() -> Result<Vec<SSHKey>, String> {
             continue;
         }
 
-        let public_key_path = path.with_extension("pub");
+        let public_key_path = public_key_path_for(&path);
         if !public_key_path.exists() {
             continue;
         }
 
-        if let Ok(key) = read_ssh_key(&path) {
+        if let Ok(mut key) = read_ssh_key(&path) {
+            key.loaded_in_agent = agent_fingerprints.contains(&key.fingerprint);
             keys.push(key);
         }
     }
@@ -209,14 +253,7 @@ pub fn add_key_to_agent(private_key_path: &Path, passphrase: Option<&str>) -> Re
         return Err("Private key not found.".to_string());
     }
 
-    if cfg!(target_os = "windows") {
-        let _ = Command::new("powershell")
-            .args([
-                "-Command",
-                "Start-Service ssh-agent -ErrorAction SilentlyContinue",
-            ])
-            .output();
-    }
+    ensure_windows_agent_running();
 
     let mut cmd = Command::new("ssh-add");
     if cfg!(target_os = "macos") {
@@ -264,35 +301,115 @@ pub fn add_key_to_agent(private_key_path: &Path, passphrase: Option<&str>) -> Re
     Ok(())
 }
 
-pub fn test_github_connection(private_key_path: &Path) -> Result<bool, String> {
-    let output = Command::new("ssh")
-        .args([
-            "-i",
-            private_key_path
-                .to_str()
-                .ok_or_else(|| "Invalid key path".to_string())?,
-            "-T",
-            "-o",
-            "BatchMode=yes",
-            "-o",
-            "StrictHostKeyChecking=no",
-            "git@github.com",
-        ])
+pub fn remove_key_from_agent(private_key_path: &Path) -> Result<(), String> {
+    if !private_key_path.exists() && !public_key_path_for(private_key_path).exists() {
+        return Err("Key not found.".to_string());
+    }
+
+    ensure_windows_agent_running();
+
+    let output = Command::new("ssh-add")
+        .arg("-d")
+        .arg(private_key_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to run ssh-add: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("ssh-add failed: {}", stderr.trim()));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SshHostTestResult {
+    pub host: String,
+    pub authenticated: bool,
+    pub banner: String,
+}
+
+fn is_authenticated_banner(banner: &str) -> bool {
+    let lower = banner.to_lowercase();
+    if lower.contains("permission denied") {
+        return false;
+    }
+    // GitHub/Gitea/Forgejo: "successfully authenticated", GitLab: "Welcome to GitLab",
+    // Bitbucket: "authenticated via ssh key" / "logged in as".
+    lower.contains("successfully authenticated")
+        || lower.contains("welcome to gitlab")
+        || lower.contains("authenticated via")
+        || lower.contains("logged in as")
+}
+
+pub fn test_host_connection(host: &str, private_key_path: &Path) -> Result<SshHostTestResult, String> {
+    let host = host.trim();
+    if host.is_empty() {
+        return Err("Host is required".to_string());
+    }
+
+    let (host_part, port) = match host.rsplit_once(':') {
+        Some((name, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => {
+            (name, Some(port))
+        }
+        _ => (host, None),
+    };
+    let destination = if host_part.contains('@') {
+        host_part.to_string()
+    } else {
+        format!("git@{}", host_part)
+    };
+
+    let mut cmd = Command::new("ssh");
+    cmd.args([
+        "-i",
+        private_key_path
+            .to_str()
+            .ok_or_else(|| "Invalid key path".to_string())?,
+        "-T",
+        "-o",
+        "BatchMode=yes",
+        "-o",
+        "StrictHostKeyChecking=no",
+    ]);
+    if let Some(port) = port {
+        cmd.args(["-p", port]);
+    }
+    cmd.arg(&destination);
+
+    let output = cmd
         .output()
         .map_err(|e| format!("Failed to run ssh: {e}"))?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
-    let combined = format!("{stdout}\n{stderr}");
-    Ok(combined.contains("successfully authenticated"))
+    let banner = format!("{}\n{}", stdout.trim(), stderr.trim())
+        .trim()
+        .to_string();
+    Ok(SshHostTestResult {
+        host: host.to_string(),
+        authenticated: is_authenticated_banner(&banner),
+        banner,
+    })
+}
+
+pub fn test_github_connection(private_key_path: &Path) -> Result<bool, String> {
+    Ok(test_host_connection("github.com", private_key_path)?.authenticated)
 }
 
 #[tauri::command]
 pub async fn generate_new_ssh_key(
     name: String,
     passphrase: Option<String>,
-    key_type: String,
+    key_type: Option<String>,
 ) -> Result<SSHKey, String> {
+    let key_type = key_type
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "ed25519".to_string());
     run_blocking(move || generate_ssh_key(&name, passphrase.as_deref(), &key_type)).await
 }
 
@@ -310,11 +427,24 @@ pub async fn add_ssh_key_to_agent(
         .await
 }
 
+#[tauri::command]
+pub async fn remove_ssh_key_from_agent(private_key_path: String) -> Result<(), String> {
+    run_blocking(move || remove_key_from_agent(Path::new(&private_key_path))).await
+}
+
 #[tauri::command]
 pub async fn test_ssh_github(private_key_path: String) -> Result<bool, String> {
     run_blocking(move || test_github_connection(Path::new(&private_key_path))).await
 }
 
+#[tauri::command]
+pub async fn test_ssh_host(
+    host: String,
+    private_key_path: String,
+) -> Result<SshHostTestResult, String> {
+    run_blocking(move || test_host_connection(&host, Path::new(&private_key_path))).await
+}
+
 #[tauri::command]
 pub async fn get_current_os() -> String {
     detect_os()
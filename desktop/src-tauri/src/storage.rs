@@ -1,14 +1,31 @@
+use crate::git;
 use git2::Repository;
 use keyring_core::{Entry, Error as KeyringError};
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::OnceLock;
+use std::time::Duration;
 use tauri::{AppHandle, Manager, Runtime};
 
 const DEFAULT_REPO_DIR_KEY: &str = "default_repo_dir";
-const BACKEND_MODE_KEY: &str = "backend_mode";
+const LEGACY_BACKEND_MODE_KEY: &str = "backend_mode";
+const DEFAULT_BACKEND_MODE_KEY: &str = "backend_mode_default";
+const REPO_BACKEND_MODE_KEY_PREFIX: &str = "backend_mode:";
+const VM_CPUS_KEY: &str = "vm_cpus";
+const VM_MEMORY_GIB_KEY: &str = "vm_memory_gib";
+const VM_DISK_GIB_KEY: &str = "vm_disk_gib";
+const STOP_VM_ON_EXIT_KEY: &str = "stop_vm_on_exit";
+const GIT_NETWORK_TIMEOUT_SECS_KEY: &str = "git_network_timeout_secs";
+const GITHUB_API_BASE_URL_KEY: &str = "github_base_url";
+const GITHUB_OAUTH_BASE_URL_KEY: &str = "github_oauth_base_url";
+pub const DEFAULT_GITHUB_API_BASE_URL: &str = "https://api.github.com";
+pub const DEFAULT_GITHUB_OAUTH_BASE_URL: &str = "https://github.com";
 const GITHUB_TOKEN_SERVICE_SUFFIX: &str = "github";
 const GITHUB_TOKEN_USERNAME: &str = "access_token";
+const SECRETS_VAULT_SERVICE_SUFFIX: &str = "secrets";
+const SECRETS_VAULT_USERNAME: &str = "vault_key";
+const SECRETS_VAULT_KEY_LEN: usize = 32;
 
 static KEYRING_INIT: OnceLock<Result<(), String>> = OnceLock::new();
 
@@ -17,6 +34,28 @@ pub struct SavedRepo {
     pub name: String,
     pub path: String,
     pub last_opened: i64,
+    pub ssh_key_path: Option<String>,
+    pub tags: Vec<String>,
+    pub valid: bool,
+    pub status: SavedRepoStatus,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedRepoStatus {
+    Ok,
+    Missing,
+    NotARepo,
+}
+
+fn saved_repo_status(path: &str) -> SavedRepoStatus {
+    if !Path::new(path).exists() {
+        SavedRepoStatus::Missing
+    } else if Repository::open(path).is_err() {
+        SavedRepoStatus::NotARepo
+    } else {
+        SavedRepoStatus::Ok
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -31,17 +70,28 @@ pub struct StoredContainer {
     pub last_used: i64,
 }
 
+/// Resource limits applied to Lima VMs. `None` keeps the template default.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VmResources {
+    pub vm_cpus: Option<u32>,
+    pub vm_memory_gib: Option<u32>,
+    pub vm_disk_gib: Option<u32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum BackendMode {
     Host,
     Virtualized,
+    /// Commands run in a Docker/Podman container on the host, no VM required.
+    Container,
 }
 
 fn parse_backend_mode(value: &str) -> Option<BackendMode> {
     match value.trim().to_lowercase().as_str() {
         "host" => Some(BackendMode::Host),
         "virtualized" => Some(BackendMode::Virtualized),
+        "container" => Some(BackendMode::Container),
         _ => None,
     }
 }
@@ -82,9 +132,60 @@ fn open_db<R: Runtime>(app: &AppHandle<R>) -> Result<Connection, String> {
         [],
     )
     .map_err(|e| e.to_string())?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS repo_tags (
+            path TEXT NOT NULL,
+            tag TEXT NOT NULL,
+            PRIMARY KEY (path, tag)
+        )",
+        [],
+    )
+    .map_err(|e| e.to_string())?;
+    ensure_column(&conn, "repos", "ssh_key_path", "TEXT")?;
+    migrate_legacy_backend_mode(&conn)?;
     Ok(conn)
 }
 
+// Adds a column to a table created by an older build.
+fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| e.to_string())?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .any(|name| name == column);
+    if !exists {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Older builds stored a single global backend mode; it becomes the default.
+fn migrate_legacy_backend_mode(conn: &Connection) -> Result<(), String> {
+    if let Some(value) = get_setting(conn, LEGACY_BACKEND_MODE_KEY)? {
+        if get_setting(conn, DEFAULT_BACKEND_MODE_KEY)?.is_none() {
+            set_setting(conn, DEFAULT_BACKEND_MODE_KEY, &value)?;
+        }
+        conn.execute(
+            "DELETE FROM settings WHERE key = ?1",
+            params![LEGACY_BACKEND_MODE_KEY],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 fn default_repo_dir<R: Runtime>(app: &AppHandle<R>) -> Result<String, String> {
     let home_dir = app.path().home_dir().map_err(|e| e.to_string())?;
     Ok(home_dir.join("falck").to_string_lossy().to_string())
@@ -106,6 +207,28 @@ fn github_token_entry<R: Runtime>(app: &AppHandle<R>) -> Result<Entry, String> {
     Entry::new(&service, GITHUB_TOKEN_USERNAME).map_err(|e| e.to_string())
 }
 
+fn secrets_vault_entry<R: Runtime>(app: &AppHandle<R>) -> Result<Entry, String> {
+    ensure_keyring_store()?;
+    let identifier = app.config().identifier.clone();
+    let service = format!("{}.{}", identifier, SECRETS_VAULT_SERVICE_SUFFIX);
+    Entry::new(&service, SECRETS_VAULT_USERNAME).map_err(|e| e.to_string())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    let value = value.trim();
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&value[index..index + 2], 16).ok())
+        .collect()
+}
+
 fn is_missing_keyring_entry(err: &KeyringError) -> bool {
     matches!(err, KeyringError::NoEntry)
 }
@@ -150,43 +273,192 @@ pub fn set_default_repo_dir<R: Runtime>(app: &AppHandle<R>, path: &str) -> Resul
     Ok(())
 }
 
+fn repo_backend_mode_key(repo_path: &Path) -> String {
+    let path = repo_path.to_string_lossy();
+    let trimmed = path.trim_end_matches(['/', '\\']);
+    let normalized = if trimmed.is_empty() {
+        path.as_ref()
+    } else {
+        trimmed
+    };
+    format!("{}{}", REPO_BACKEND_MODE_KEY_PREFIX, normalized)
+}
+
+fn backend_mode_value(mode: BackendMode) -> &'static str {
+    match mode {
+        BackendMode::Host => "host",
+        BackendMode::Virtualized => "virtualized",
+        BackendMode::Container => "container",
+    }
+}
+
+/// Returns the backend mode chosen for `repo_path`, falling back to the
+/// global default. `None` means nothing has been chosen yet.
 pub fn get_backend_mode_raw<R: Runtime>(
     app: &AppHandle<R>,
+    repo_path: Option<&Path>,
 ) -> Result<Option<BackendMode>, String> {
     let conn = open_db(app)?;
-    let mut stmt = conn
-        .prepare("SELECT value FROM settings WHERE key = ?1")
-        .map_err(|e| e.to_string())?;
-    let mut rows = stmt
-        .query(params![BACKEND_MODE_KEY])
-        .map_err(|e| e.to_string())?;
-    if let Some(row) = rows.next().map_err(|e| e.to_string())? {
-        let value: String = row.get(0).map_err(|e| e.to_string())?;
-        if let Some(mode) = parse_backend_mode(&value) {
+    if let Some(repo_path) = repo_path {
+        let key = repo_backend_mode_key(repo_path);
+        if let Some(mode) = get_setting(&conn, &key)?.and_then(|value| parse_backend_mode(&value)) {
             return Ok(Some(mode));
         }
     }
-    Ok(None)
+    Ok(get_setting(&conn, DEFAULT_BACKEND_MODE_KEY)?.and_then(|value| parse_backend_mode(&value)))
 }
 
+/// Stores the backend mode for `repo_path`, or the global default when no
+/// repository is given.
 pub fn set_backend_mode<R: Runtime>(
     app: &AppHandle<R>,
+    repo_path: Option<&Path>,
     mode: BackendMode,
 ) -> Result<(), String> {
     let conn = open_db(app)?;
-    let value = match mode {
-        BackendMode::Host => "host",
-        BackendMode::Virtualized => "virtualized",
+    let key = match repo_path {
+        Some(repo_path) => repo_backend_mode_key(repo_path),
+        None => DEFAULT_BACKEND_MODE_KEY.to_string(),
     };
+    set_setting(&conn, &key, backend_mode_value(mode))
+}
+
+fn get_u32_setting(conn: &Connection, key: &str) -> Result<Option<u32>, String> {
+    Ok(get_setting(conn, key)?.and_then(|value| value.trim().parse::<u32>().ok()))
+}
+
+fn set_u32_setting(conn: &Connection, key: &str, value: Option<u32>) -> Result<(), String> {
+    match value {
+        Some(value) => set_setting(conn, key, &value.to_string()),
+        None => {
+            conn.execute("DELETE FROM settings WHERE key = ?1", params![key])
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        }
+    }
+}
+
+pub fn get_vm_resources<R: Runtime>(app: &AppHandle<R>) -> Result<VmResources, String> {
+    let conn = open_db(app)?;
+    Ok(VmResources {
+        vm_cpus: get_u32_setting(&conn, VM_CPUS_KEY)?,
+        vm_memory_gib: get_u32_setting(&conn, VM_MEMORY_GIB_KEY)?,
+        vm_disk_gib: get_u32_setting(&conn, VM_DISK_GIB_KEY)?,
+    })
+}
+
+pub fn set_vm_resources<R: Runtime>(
+    app: &AppHandle<R>,
+    resources: &VmResources,
+) -> Result<(), String> {
+    let values = [
+        ("CPU count", resources.vm_cpus),
+        ("Memory", resources.vm_memory_gib),
+        ("Disk size", resources.vm_disk_gib),
+    ];
+    for (label, value) in values {
+        if value == Some(0) {
+            return Err(format!("{} must be greater than zero.", label));
+        }
+    }
+    let conn = open_db(app)?;
+    set_u32_setting(&conn, VM_CPUS_KEY, resources.vm_cpus)?;
+    set_u32_setting(&conn, VM_MEMORY_GIB_KEY, resources.vm_memory_gib)?;
+    set_u32_setting(&conn, VM_DISK_GIB_KEY, resources.vm_disk_gib)?;
+    Ok(())
+}
+
+/// Whether repo VMs are stopped when the app closes. Defaults to true.
+pub fn get_stop_vm_on_exit<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
+    let conn = open_db(app)?;
+    Ok(get_setting(&conn, STOP_VM_ON_EXIT_KEY)?
+        .map(|value| value != "false")
+        .unwrap_or(true))
+}
+
+pub fn set_stop_vm_on_exit<R: Runtime>(app: &AppHandle<R>, enabled: bool) -> Result<(), String> {
+    let conn = open_db(app)?;
+    let value = if enabled { "true" } else { "false" };
+    set_setting(&conn, STOP_VM_ON_EXIT_KEY, value)
+}
+
+/// Seconds a clone, push or pull may go without progress before it is abandoned.
+pub fn get_git_network_timeout<R: Runtime>(app: &AppHandle<R>) -> Result<Duration, String> {
+    let conn = open_db(app)?;
+    Ok(get_u32_setting(&conn, GIT_NETWORK_TIMEOUT_SECS_KEY)?
+        .filter(|secs| *secs > 0)
+        .map(|secs| Duration::from_secs(u64::from(secs)))
+        .unwrap_or(git::DEFAULT_NETWORK_TIMEOUT))
+}
+
+pub fn set_git_network_timeout<R: Runtime>(
+    app: &AppHandle<R>,
+    seconds: Option<u32>,
+) -> Result<(), String> {
+    if seconds == Some(0) {
+        return Err("Git network timeout must be greater than zero.".to_string());
+    }
+    let conn = open_db(app)?;
+    set_u32_setting(&conn, GIT_NETWORK_TIMEOUT_SECS_KEY, seconds)
+}
+
+fn get_setting(conn: &Connection, key: &str) -> Result<Option<String>, String> {
+    let mut stmt = conn
+        .prepare("SELECT value FROM settings WHERE key = ?1")
+        .map_err(|e| e.to_string())?;
+    let mut rows = stmt.query(params![key]).map_err(|e| e.to_string())?;
+    if let Some(row) = rows.next().map_err(|e| e.to_string())? {
+        let value: String = row.get(0).map_err(|e| e.to_string())?;
+        if !value.trim().is_empty() {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+fn set_setting(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
     conn.execute(
         "INSERT INTO settings (key, value) VALUES (?1, ?2)
          ON CONFLICT(key) DO UPDATE SET value = excluded.value",
-        params![BACKEND_MODE_KEY, value],
+        params![key, value],
     )
     .map_err(|e| e.to_string())?;
     Ok(())
 }
 
+fn normalize_base_url(url: &str) -> Result<String, String> {
+    let trimmed = url.trim().trim_end_matches('/');
+    if !(trimmed.starts_with("https://") || trimmed.starts_with("http://")) {
+        return Err("GitHub URL must start with http:// or https://.".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+pub fn get_github_api_base_url<R: Runtime>(app: &AppHandle<R>) -> Result<String, String> {
+    let conn = open_db(app)?;
+    Ok(get_setting(&conn, GITHUB_API_BASE_URL_KEY)?
+        .unwrap_or_else(|| DEFAULT_GITHUB_API_BASE_URL.to_string()))
+}
+
+pub fn get_github_oauth_base_url<R: Runtime>(app: &AppHandle<R>) -> Result<String, String> {
+    let conn = open_db(app)?;
+    Ok(get_setting(&conn, GITHUB_OAUTH_BASE_URL_KEY)?
+        .unwrap_or_else(|| DEFAULT_GITHUB_OAUTH_BASE_URL.to_string()))
+}
+
+pub fn set_github_base_urls<R: Runtime>(
+    app: &AppHandle<R>,
+    api_base_url: &str,
+    oauth_base_url: &str,
+) -> Result<(), String> {
+    let api_base_url = normalize_base_url(api_base_url)?;
+    let oauth_base_url = normalize_base_url(oauth_base_url)?;
+    let conn = open_db(app)?;
+    set_setting(&conn, GITHUB_API_BASE_URL_KEY, &api_base_url)?;
+    set_setting(&conn, GITHUB_OAUTH_BASE_URL_KEY, &oauth_base_url)?;
+    Ok(())
+}
+
 pub fn get_github_token<R: Runtime>(app: &AppHandle<R>) -> Result<Option<String>, String> {
     let entry = github_token_entry(app)?;
     match entry.get_password() {
@@ -216,37 +488,97 @@ pub fn clear_github_token<R: Runtime>(app: &AppHandle<R>) -> Result<(), String>
     Ok(())
 }
 
+pub fn get_or_create_secrets_vault_key<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Vec<u8>, String> {
+    let entry = secrets_vault_entry(app)?;
+    match entry.get_password() {
+        Ok(value) => {
+            if let Some(key) = decode_hex(&value).filter(|key| key.len() == SECRETS_VAULT_KEY_LEN) {
+                return Ok(key);
+            }
+            Err("Secrets vault key in the keychain is corrupted.".to_string())
+        }
+        Err(err) if is_missing_keyring_entry(&err) => {
+            let mut key = vec![0u8; SECRETS_VAULT_KEY_LEN];
+            getrandom::getrandom(&mut key).map_err(|e| e.to_string())?;
+            entry
+                .set_password(&encode_hex(&key))
+                .map_err(|e| e.to_string())?;
+            Ok(key)
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 pub fn save_repo<R: Runtime>(
     app: &AppHandle<R>,
     name: &str,
     path: &str,
     last_opened: i64,
+    ssh_key_path: Option<&str>,
 ) -> Result<(), String> {
     Repository::open(path).map_err(|_| "Repository not found".to_string())?;
     let conn = open_db(app)?;
     conn.execute(
-        "INSERT INTO repos (path, name, last_opened)
-         VALUES (?1, ?2, ?3)
+        "INSERT INTO repos (path, name, last_opened, ssh_key_path)
+         VALUES (?1, ?2, ?3, ?4)
          ON CONFLICT(path) DO UPDATE SET
             name = excluded.name,
-            last_opened = excluded.last_opened",
-        params![path, name, last_opened],
+            last_opened = excluded.last_opened,
+            ssh_key_path = COALESCE(excluded.ssh_key_path, repos.ssh_key_path)",
+        params![path, name, last_opened, ssh_key_path],
     )
     .map_err(|e| e.to_string())?;
     Ok(())
 }
 
+/// Remembers the SSH key used with a saved repo. Does nothing for repos that
+/// are not saved.
+pub fn set_repo_ssh_key<R: Runtime>(
+    app: &AppHandle<R>,
+    path: &str,
+    ssh_key_path: Option<&str>,
+) -> Result<(), String> {
+    let conn = open_db(app)?;
+    conn.execute(
+        "UPDATE repos SET ssh_key_path = ?2 WHERE path = ?1",
+        params![path, ssh_key_path],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn get_repo_ssh_key<R: Runtime>(
+    app: &AppHandle<R>,
+    path: &str,
+) -> Result<Option<String>, String> {
+    let conn = open_db(app)?;
+    let mut stmt = conn
+        .prepare("SELECT ssh_key_path FROM repos WHERE path = ?1")
+        .map_err(|e| e.to_string())?;
+    let mut rows = stmt.query(params![path]).map_err(|e| e.to_string())?;
+    match rows.next().map_err(|e| e.to_string())? {
+        Some(row) => row.get::<_, Option<String>>(0).map_err(|e| e.to_string()),
+        None => Ok(None),
+    }
+}
+
 pub fn remove_repo<R: Runtime>(app: &AppHandle<R>, path: &str) -> Result<(), String> {
     let conn = open_db(app)?;
     conn.execute("DELETE FROM repos WHERE path = ?1", params![path])
         .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM repo_tags WHERE path = ?1", params![path])
+        .map_err(|e| e.to_string())?;
     Ok(())
 }
 
 pub fn list_repos<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<SavedRepo>, String> {
     let conn = open_db(app)?;
     let mut stmt = conn
-        .prepare("SELECT name, path, last_opened FROM repos ORDER BY last_opened DESC")
+        .prepare(
+            "SELECT name, path, last_opened, ssh_key_path FROM repos ORDER BY last_opened DESC",
+        )
         .map_err(|e| e.to_string())?;
 
     let rows = stmt
@@ -255,33 +587,84 @@ pub fn list_repos<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<SavedRepo>, Stri
                 name: row.get(0)?,
                 path: row.get(1)?,
                 last_opened: row.get(2)?,
+                ssh_key_path: row.get(3)?,
+                tags: Vec::new(),
+                valid: false,
+                status: SavedRepoStatus::Missing,
             })
         })
         .map_err(|e| e.to_string())?;
 
     let mut repos = Vec::new();
-    let mut stale_paths = Vec::new();
     for row in rows {
-        let repo = row.map_err(|e| e.to_string())?;
-        if Repository::open(&repo.path).is_ok() {
-            repos.push(repo);
-        } else {
-            stale_paths.push(repo.path);
-        }
+        let mut repo = row.map_err(|e| e.to_string())?;
+        repo.status = saved_repo_status(&repo.path);
+        repo.valid = repo.status == SavedRepoStatus::Ok;
+        repo.tags = repo_tags(&conn, &repo.path)?;
+        repos.push(repo);
     }
 
-    if !stale_paths.is_empty() {
-        let mut delete_stmt = conn
-            .prepare("DELETE FROM repos WHERE path = ?1")
-            .map_err(|e| e.to_string())?;
-        for path in stale_paths {
-            delete_stmt
-                .execute(params![path])
-                .map_err(|e| e.to_string())?;
+    Ok(repos)
+}
+
+/// Drops saved repos whose paths no longer exist. Returns the removed paths.
+pub fn prune_missing_repos<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<String>, String> {
+    let missing: Vec<String> = list_repos(app)?
+        .into_iter()
+        .filter(|repo| repo.status == SavedRepoStatus::Missing)
+        .map(|repo| repo.path)
+        .collect();
+    for path in &missing {
+        remove_repo(app, path)?;
+    }
+    Ok(missing)
+}
+
+fn repo_tags(conn: &Connection, path: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare("SELECT tag FROM repo_tags WHERE path = ?1 ORDER BY tag")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![path], |row| row.get::<_, String>(0))
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())
+}
+
+/// Replaces the tags of a saved repo. Tags are trimmed and deduplicated.
+pub fn set_repo_tags<R: Runtime>(
+    app: &AppHandle<R>,
+    path: &str,
+    tags: &[String],
+) -> Result<(), String> {
+    let mut conn = open_db(app)?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute("DELETE FROM repo_tags WHERE path = ?1", params![path])
+        .map_err(|e| e.to_string())?;
+    for tag in tags {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            continue;
         }
+        tx.execute(
+            "INSERT OR IGNORE INTO repo_tags (path, tag) VALUES (?1, ?2)",
+            params![path, tag],
+        )
+        .map_err(|e| e.to_string())?;
     }
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-    Ok(repos)
+pub fn list_repos_by_tag<R: Runtime>(
+    app: &AppHandle<R>,
+    tag: &str,
+) -> Result<Vec<SavedRepo>, String> {
+    let tag = tag.trim();
+    Ok(list_repos(app)?
+        .into_iter()
+        .filter(|repo| repo.tags.iter().any(|value| value == tag))
+        .collect())
 }
 
 pub fn reset_storage<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
@@ -292,6 +675,8 @@ pub fn reset_storage<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
         .map_err(|e| e.to_string())?;
     conn.execute("DELETE FROM containers", [])
         .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM repo_tags", [])
+        .map_err(|e| e.to_string())?;
     Ok(())
 }
 
@@ -71,7 +71,7 @@ export function CommitHistory({
     setRestoreLoading(true);
     setRestoreError(null);
     try {
-      const info = await gitService.getRepositoryInfo(repoPath);
+      const info = await gitService.getRepositoryInfo(repoPath, true);
       if (info.is_dirty) {
         setRestoreError(
           "Please save or discard your changes before restoring a version.",
@@ -455,16 +455,33 @@ export function RepoSelector({
                     {visibleSavedRepos.map((repo) => (
                       <div
                         key={repo.path}
-                        className="flex flex-col gap-4 rounded-2xl border border-border/60 bg-background/70 p-4 transition"
+                        className={`flex flex-col gap-4 rounded-2xl border p-4 transition ${
+                          repo.valid
+                            ? "border-border/60 bg-background/70"
+                            : "border-destructive/40 bg-destructive/5"
+                        }`}
                       >
                         <div className="flex flex-wrap items-start justify-between gap-3">
                           <div>
-                            <div className="text-base font-semibold text-foreground">
+                            <div className="flex items-center gap-2 text-base font-semibold text-foreground">
                               {repo.name}
+                              {!repo.valid && (
+                                <Badge variant="destructive">
+                                  {repo.status === "missing"
+                                    ? "Folder missing"
+                                    : "Not a Git repository"}
+                                </Badge>
+                              )}
                             </div>
                             <div className="text-xs font-mono text-muted-foreground break-all">
                               {repo.path}
                             </div>
+                            {!repo.valid && (
+                              <div className="mt-1 text-xs text-muted-foreground">
+                                Moved or deleted? Remove it here and open the
+                                new location below.
+                              </div>
+                            )}
                           </div>
                           <div className="flex items-center gap-2">
                             <Button
@@ -472,7 +489,7 @@ export function RepoSelector({
                               onClick={() =>
                                 handleOpenSaved(repo.path, repo.name)
                               }
-                              disabled={loading || removingRepo}
+                              disabled={loading || removingRepo || !repo.valid}
                               className="normal-case tracking-normal"
                             >
                               Open
@@ -79,7 +79,7 @@ export function SaveChangesDialog({
 
   const loadStatus = async () => {
     try {
-      const info = await gitService.getRepositoryInfo(repoPath);
+      const info = await gitService.getRepositoryInfo(repoPath, true);
       setFiles(info.status_files);
       setSelectedFiles(new Set(info.status_files.map((file) => file.path)));
       setError(null);
@@ -62,6 +62,10 @@ const MODE_COPY: Record<BackendMode, { title: string; description: string }> = {
     title: "Managed workspace",
     description: "A clean, isolated setup with tools handled for you.",
   },
+  container: {
+    title: "Local container",
+    description: "Runs in Docker or Podman on this computer, no VM needed.",
+  },
 };
 
 const KEY_TYPE_LABELS: Record<KeyType, string> = {
@@ -49,7 +49,7 @@ export function StagingArea({ repoPath, onCommit }: StagingAreaProps) {
 
   const loadStatus = async () => {
     try {
-      const info = await gitService.getRepositoryInfo(repoPath);
+      const info = await gitService.getRepositoryInfo(repoPath, true);
       setFiles(info.status_files);
     } catch (err) {
       console.error("Failed to load status:", err);
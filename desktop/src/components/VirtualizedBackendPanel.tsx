@@ -32,6 +32,7 @@ interface VirtualizedBackendPanelProps {
 const MODE_LABELS: Record<BackendMode, string> = {
   host: "On this computer",
   virtualized: "In a virtual workspace",
+  container: "In a local container",
 };
 
 function normalizeStatus(status: string) {
@@ -80,6 +81,9 @@ export function VirtualizedBackendPanel({
   className,
 }: VirtualizedBackendPanelProps) {
   const [mode, setMode] = useState<BackendMode>("host");
+  const [supportedModes, setSupportedModes] = useState<BackendMode[] | null>(
+    null,
+  );
   const [modeSaving, setModeSaving] = useState(false);
   const [modeError, setModeError] = useState<string | null>(null);
 
@@ -95,8 +99,12 @@ export function VirtualizedBackendPanel({
   const loadMode = useCallback(async () => {
     setModeError(null);
     try {
-      const nextMode = await backendService.getMode();
+      const [nextMode, modes] = await Promise.all([
+        backendService.getMode(),
+        backendService.getSupportedModes(),
+      ]);
       setMode(nextMode);
+      setSupportedModes(modes);
     } catch (err) {
       setModeError(`Failed to load backend mode: ${String(err)}`);
     }
@@ -211,6 +219,8 @@ export function VirtualizedBackendPanel({
   }, [vms]);
 
   const prereqInstalled = prereq?.installed ?? false;
+  const containerModeSupported =
+    supportedModes?.includes("container") ?? false;
   const toolLabel = prereq?.tool ?? "Virtualization";
 
   return (
@@ -262,6 +272,14 @@ export function VirtualizedBackendPanel({
                   <SelectItem value="virtualized">
                     {MODE_LABELS.virtualized}
                   </SelectItem>
+                  <SelectItem
+                    value="container"
+                    disabled={!containerModeSupported}
+                  >
+                    {containerModeSupported
+                      ? MODE_LABELS.container
+                      : `${MODE_LABELS.container} (Linux only)`}
+                  </SelectItem>
                 </SelectContent>
               </Select>
             </div>
@@ -77,7 +77,7 @@ export const SecretsDialog: React.FC<SecretsDialogProps> = ({
     try {
       for (const [name, value] of Object.entries(secretValues)) {
         if (value) {
-          await falckService.setSecret(name, value);
+          await falckService.setSecret(repoPath, name, value);
         }
       }
       onSecretsSaved();
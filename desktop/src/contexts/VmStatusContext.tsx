@@ -8,7 +8,11 @@ import {
   type ReactNode,
 } from "react";
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
-import { backendService, type BackendMode } from "@/services/backendService";
+import {
+  backendService,
+  type BackendMode,
+  type BackendModeEvent,
+} from "@/services/backendService";
 import { useAppState } from "@/router/app-state";
 
 export type VmPhase =
@@ -83,6 +87,8 @@ export function VmStatusProvider({ children }: { children: ReactNode }) {
   const [expanded, setExpanded] = useState(false);
   const previousRepoRef = useRef<string | null>(null);
   const previousModeRef = useRef<BackendMode>("host");
+  const activeRepoRef = useRef<string | null>(repoPath);
+  activeRepoRef.current = repoPath;
 
   useEffect(() => {
     let active = true;
@@ -94,7 +100,7 @@ export function VmStatusProvider({ children }: { children: ReactNode }) {
     }
 
     backendService
-      .getMode()
+      .getMode(repoPath)
       .then((nextMode) => {
         if (active) {
           setMode(nextMode);
@@ -143,12 +149,31 @@ export function VmStatusProvider({ children }: { children: ReactNode }) {
     let unlisten: UnlistenFn | null = null;
     let active = true;
 
-    listen<BackendMode>("backend:mode", (event) => {
-      if (!active) {
+    listen<BackendModeEvent>("backend:mode", (event) => {
+      if (!active || !event.payload) {
+        return;
+      }
+      const { repoPath: eventRepoPath, mode: nextMode } = event.payload;
+      const activeRepo = activeRepoRef.current;
+      if (!activeRepo) {
+        return;
+      }
+      if (eventRepoPath === activeRepo) {
+        setMode(nextMode);
         return;
       }
-      if (event.payload) {
-        setMode(event.payload);
+      if (eventRepoPath === null) {
+        // The global default changed; re-read in case this repo has no override.
+        backendService
+          .getMode(activeRepo)
+          .then((effectiveMode) => {
+            if (active && activeRepoRef.current === activeRepo) {
+              setMode(effectiveMode);
+            }
+          })
+          .catch(() => {
+            // ignore
+          });
       }
     })
       .then((stop) => {
@@ -1,10 +1,16 @@
 import { invoke } from "@tauri-apps/api/core";
 
-export type BackendMode = "host" | "virtualized";
+export type BackendMode = "host" | "virtualized" | "container";
+
+export interface BackendModeEvent {
+  repoPath: string | null;
+  mode: BackendMode;
+}
 
 export interface BackendPrereqStatus {
   installed: boolean;
   tool: string;
+  version?: string | null;
   message?: string;
 }
 
@@ -21,13 +27,48 @@ export interface BackendVmInfo {
   repo_path?: string | null;
 }
 
+export interface CommandOutput {
+  exit_code?: number | null;
+  stdout: string;
+  stderr: string;
+}
+
+export interface BackendStats {
+  cpus: number;
+  memory_total_mb: number;
+  memory_used_mb: number;
+  disk_total_mb: number;
+  disk_used_mb: number;
+}
+
+export interface BackendCapabilities {
+  nerdctl: boolean;
+  sudo_nopasswd: boolean;
+  bootstrap_done: boolean;
+}
+
+export interface VmResources {
+  vm_cpus?: number | null;
+  vm_memory_gib?: number | null;
+  vm_disk_gib?: number | null;
+}
+
 export const backendService = {
-  async getMode(): Promise<BackendMode> {
-    return invoke<BackendMode>("get_backend_mode");
+  async getMode(repoPath?: string): Promise<BackendMode> {
+    return invoke<BackendMode>("get_backend_mode", {
+      repoPath: repoPath ?? null,
+    });
+  },
+
+  async setMode(mode: BackendMode, repoPath?: string): Promise<void> {
+    return invoke<void>("set_backend_mode", {
+      mode,
+      repoPath: repoPath ?? null,
+    });
   },
 
-  async setMode(mode: BackendMode): Promise<void> {
-    return invoke<void>("set_backend_mode", { mode });
+  async getSupportedModes(): Promise<BackendMode[]> {
+    return invoke<BackendMode[]>("get_supported_backend_modes");
   },
 
   async checkPrereq(): Promise<BackendPrereqStatus> {
@@ -38,6 +79,28 @@ export const backendService = {
     return invoke<BackendEnsureResult>("ensure_repo_backend", { repoPath });
   },
 
+  async runCommand(repoPath: string, command: string): Promise<CommandOutput> {
+    return invoke<CommandOutput>("run_backend_command", { repoPath, command });
+  },
+
+  async cancelOperation(repoPath: string): Promise<boolean> {
+    return invoke<boolean>("cancel_backend_operation", { repoPath });
+  },
+
+  async getStats(repoPath: string): Promise<BackendStats> {
+    return invoke<BackendStats>("get_backend_stats", { repoPath });
+  },
+
+  async getCapabilities(repoPath: string): Promise<BackendCapabilities> {
+    return invoke<BackendCapabilities>("get_backend_capabilities", {
+      repoPath,
+    });
+  },
+
+  async getVmLogs(repoPath: string): Promise<string> {
+    return invoke<string>("get_vm_logs", { repoPath });
+  },
+
   async stopRepoBackend(repoPath: string): Promise<void> {
     return invoke<void>("stop_repo_backend", { repoPath });
   },
@@ -46,10 +109,40 @@ export const backendService = {
     return invoke<void>("delete_repo_backend", { repoPath });
   },
 
+  async resetRepoBackend(repoPath: string): Promise<BackendEnsureResult> {
+    return invoke<BackendEnsureResult>("reset_repo_backend", { repoPath });
+  },
+
+  async pruneVmPortForwards(repoPath: string): Promise<number[]> {
+    return invoke<number[]>("prune_vm_port_forwards", { repoPath });
+  },
+
   async listVms(): Promise<BackendVmInfo[]> {
     return invoke<BackendVmInfo[]>("list_backend_vms");
   },
 
+  async getVmResources(): Promise<VmResources> {
+    return invoke<VmResources>("get_vm_resources");
+  },
+
+  // Returns the VMs that need a reset before the new values apply.
+  async setVmResources(resources: VmResources): Promise<string[]> {
+    return invoke<string[]>("set_vm_resources", { resources });
+  },
+
+  // Returns the names of the VMs that were stopped.
+  async stopAllBackends(): Promise<string[]> {
+    return invoke<string[]>("stop_all_backends");
+  },
+
+  async getStopVmOnExit(): Promise<boolean> {
+    return invoke<boolean>("get_stop_vm_on_exit");
+  },
+
+  async setStopVmOnExit(enabled: boolean): Promise<void> {
+    return invoke<void>("set_stop_vm_on_exit", { enabled });
+  },
+
   async stopVm(name: string): Promise<void> {
     return invoke<void>("stop_backend_vm", { name });
   },
@@ -13,6 +13,7 @@ export interface FalckConfig {
     default_branch?: string;
     protect_default_branch?: boolean;
     branch_prefix?: string;
+    env_file?: string;
   };
   applications: FalckApplication[];
   global_env?: Record<string, string>;
@@ -85,6 +86,8 @@ export interface SetupStep {
   silent?: boolean;
   optional?: boolean;
   only_if?: string;
+  retries?: number;
+  retry_delay_secs?: number;
 }
 
 export interface SetupCheck {
@@ -107,6 +110,16 @@ export interface SetupCheckResult {
   message?: string;
 }
 
+export interface SetupProgressEvent {
+  repoPath: string;
+  appId: string;
+  stepName: string;
+  index: number;
+  total: number;
+  status: "running" | "completed" | "skipped" | "failed";
+  stderr?: string | null;
+}
+
 export interface ContainerHandle {
   id: string;
   name: string;
@@ -126,6 +139,7 @@ export interface LaunchConfig {
   env?: Record<string, string>;
   ports?: number[];
   container?: ContainerLaunchConfig;
+  auto_restart?: boolean;
 }
 
 export interface ContainerLaunchConfig {
@@ -146,6 +160,22 @@ export interface ContainerMount {
   mode?: string;
 }
 
+export interface AppExitedEvent {
+  repoPath: string;
+  appId: string;
+  handle: number;
+  restarting: boolean;
+}
+
+export interface RunningAppInfo {
+  handle: number;
+  repoPath: string;
+  appId: string;
+  backend: "host" | "lima" | "wsl" | "container";
+  pid: number;
+  instance?: string | null;
+}
+
 export interface AccessConfig {
   type: string;
   url?: string;
@@ -186,6 +216,12 @@ export interface PrerequisiteCheckResult {
   optional: boolean;
 }
 
+export interface ValidationIssue {
+  severity: "error" | "warning";
+  message: string;
+  app_id?: string;
+}
+
 export const falckService = {
   async loadConfig(repoPath: string): Promise<FalckConfig> {
     return invoke<FalckConfig>("load_falck_config", {
@@ -193,6 +229,26 @@ export const falckService = {
     });
   },
 
+  async validateConfig(repoPath: string): Promise<ValidationIssue[]> {
+    return invoke<ValidationIssue[]>("validate_falck_config", {
+      repoPath,
+    });
+  },
+
+  async scaffoldConfig(
+    repoPath: string,
+    appName: string,
+    appType: string,
+    force = false,
+  ): Promise<string> {
+    return invoke<string>("scaffold_falck_config", {
+      repoPath,
+      appName,
+      appType,
+      force,
+    });
+  },
+
   async getAppSecrets(repoPath: string, appId: string): Promise<Secret[]> {
     return invoke<Secret[]>("get_app_secrets_for_config", {
       repoPath,
@@ -200,8 +256,9 @@ export const falckService = {
     });
   },
 
-  async setSecret(name: string, value: string): Promise<void> {
+  async setSecret(repoPath: string, name: string, value: string): Promise<void> {
     return invoke<void>("set_app_secret", {
+      repoPath,
       name,
       value,
     });
@@ -224,6 +281,15 @@ export const falckService = {
     });
   },
 
+  async checkAllPrerequisites(
+    repoPath: string,
+  ): Promise<Record<string, PrerequisiteCheckResult[]>> {
+    return invoke<Record<string, PrerequisiteCheckResult[]>>(
+      "check_all_prerequisites",
+      { repoPath },
+    );
+  },
+
   async runPrerequisiteInstall(
     repoPath: string,
     appId: string,
@@ -245,6 +311,12 @@ export const falckService = {
     });
   },
 
+  async runAllSetup(repoPath: string): Promise<string> {
+    return invoke<string>("run_all_falck_setup", {
+      repoPath,
+    });
+  },
+
   async checkSetupStatus(
     repoPath: string,
     appId: string,
@@ -275,6 +347,22 @@ export const falckService = {
     });
   },
 
+  async listRunningApps(): Promise<RunningAppInfo[]> {
+    return invoke<RunningAppInfo[]>("list_running_apps");
+  },
+
+  async restartApp(handle: number): Promise<LaunchResult> {
+    return invoke<LaunchResult>("restart_falck_app", {
+      handle,
+    });
+  },
+
+  async getAppLogs(handle: number): Promise<string[]> {
+    return invoke<string[]>("get_falck_app_logs", {
+      handle,
+    });
+  },
+
   async isPortAvailable(port: number): Promise<boolean> {
     return invoke<boolean>("check_port_available", {
       port,
@@ -287,8 +375,8 @@ export const falckService = {
     });
   },
 
-  async clearSecrets(): Promise<void> {
-    return invoke<void>("clear_all_secrets");
+  async clearSecrets(repoPath?: string): Promise<void> {
+    return invoke<void>("clear_all_secrets", { repoPath: repoPath ?? null });
   },
 
   async resetApp(): Promise<string> {
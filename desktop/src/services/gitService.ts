@@ -4,11 +4,44 @@ import { SSHKey } from "@/services/sshService";
 
 export interface CommitInfo {
   id: string;
+  short_id: string;
   author: string;
   message: string;
+  summary: string;
+  body: string;
   timestamp: number;
 }
 
+export interface BlameLine {
+  commit_id: string;
+  author: string;
+  start_line: number;
+  end_line: number;
+}
+
+export interface BlameResult {
+  lines: BlameLine[];
+  truncated: boolean;
+}
+
+export interface ConflictEntry {
+  path: string;
+  ancestor_id?: string | null;
+  our_id?: string | null;
+  their_id?: string | null;
+}
+
+export interface MergeResult {
+  commit_id?: string | null;
+  fast_forward: boolean;
+  conflicts: string[];
+}
+
+export interface RebaseResult {
+  completed: boolean;
+  conflicts: string[];
+}
+
 export interface BranchInfo {
   name: string;
   is_head: boolean;
@@ -18,6 +51,16 @@ export interface BranchInfo {
 export interface FileStatus {
   path: string;
   status: "modified" | "added" | "deleted" | "renamed" | "untracked" | "unknown";
+  staged: boolean;
+  index_status?: "added" | "modified" | "deleted" | "renamed" | null;
+  worktree_status?: "untracked" | "modified" | "deleted" | "renamed" | null;
+}
+
+export interface WorktreeInfo {
+  name: string;
+  path: string;
+  branch?: string | null;
+  locked: boolean;
 }
 
 export interface RepositoryInfo {
@@ -28,12 +71,42 @@ export interface RepositoryInfo {
   status_files: FileStatus[];
 }
 
+export type ForceMode = "none" | "force" | "with_lease";
+
+export interface PushOptions {
+  force?: ForceMode;
+  remoteBranch?: string | null;
+  setUpstream?: boolean;
+}
+
+export interface CurrentBranchInfo {
+  name: string;
+  detached: boolean;
+  upstream?: string | null;
+  ahead?: number | null;
+  behind?: number | null;
+}
+
+export interface RepoStatusSummary {
+  path: string;
+  head_branch: string;
+  is_dirty: boolean;
+  ahead?: number | null;
+  behind?: number | null;
+}
+
 export interface SavedRepo {
   name: string;
   path: string;
   last_opened: number;
+  ssh_key_path?: string | null;
+  tags: string[];
+  valid: boolean;
+  status: "ok" | "missing" | "not_a_repo";
 }
 
+export type CloneProtocol = "ssh" | "https";
+
 const requireSSHKey = (): SSHKey => {
   const key = configService.getSelectedSSHKey();
   if (!key) {
@@ -43,23 +116,86 @@ const requireSSHKey = (): SSHKey => {
 };
 
 export const gitService = {
-  async cloneRepository(url: string, path: string): Promise<string> {
-    const key = requireSSHKey();
+  async cloneRepository(
+    url: string,
+    path: string,
+    initSubmodules = false,
+    branch?: string | null,
+    protocol: CloneProtocol = "ssh",
+  ): Promise<string> {
+    const sshKeyPath =
+      protocol === "ssh" ? requireSSHKey().private_key_path : null;
     return invoke("clone_repo", {
       url,
+      path,
+      sshKeyPath,
+      branch: branch ?? null,
+      initSubmodules,
+      protocol,
+    });
+  },
+
+  async updateSubmodules(path: string): Promise<string> {
+    const key = requireSSHKey();
+    return invoke("update_submodules", {
       path,
       sshKeyPath: key.private_key_path,
     });
   },
 
-  async getRepositoryInfo(path: string): Promise<RepositoryInfo> {
-    return invoke("get_repo_info", { path });
+  async getRepositoryInfo(
+    path: string,
+    refresh = false,
+  ): Promise<RepositoryInfo> {
+    return invoke("get_repo_info", { path, refresh });
+  },
+
+  async getCurrentBranch(path: string): Promise<CurrentBranchInfo> {
+    return invoke("get_current_branch", { path });
+  },
+
+  async getReposStatus(paths: string[]): Promise<RepoStatusSummary[]> {
+    return invoke("get_repos_status", { paths });
+  },
+
+  async getIgnoredFiles(path: string): Promise<string[]> {
+    return invoke("get_ignored_files", { path });
+  },
+
+  async isPathIgnored(path: string, file: string): Promise<boolean> {
+    return invoke("is_path_ignored", { path, file });
   },
 
   async getCommitHistory(path: string, count = 50): Promise<CommitInfo[]> {
     return invoke("get_commits", { path, count });
   },
 
+  async searchCommits(
+    path: string,
+    query?: string | null,
+    author?: string | null,
+    maxCount = 50,
+  ): Promise<CommitInfo[]> {
+    return invoke("search_commits", {
+      path,
+      query: query ?? null,
+      author: author ?? null,
+      maxCount,
+    });
+  },
+
+  async getFileHistory(
+    path: string,
+    file: string,
+    maxCount = 50,
+  ): Promise<CommitInfo[]> {
+    return invoke("get_file_history", { path, file, maxCount });
+  },
+
+  async blameFile(path: string, file: string): Promise<BlameResult> {
+    return invoke("blame_file", { path, file });
+  },
+
   async getProjectHistory(
     path: string,
     baseBranch: string,
@@ -76,21 +212,66 @@ export const gitService = {
     return invoke("unstage", { path, file });
   },
 
+  async unstageAll(path: string): Promise<string> {
+    return invoke("unstage_all", { path });
+  },
+
   async createCommit(
     path: string,
     message: string,
     author: string,
     email: string,
+    trailers: [string, string][] = [],
+    signOff = false,
   ): Promise<string> {
-    return invoke("commit", { path, message, author, email });
+    return invoke("commit", {
+      path,
+      message,
+      author,
+      email,
+      trailers,
+      signOff,
+    });
   },
 
   async resetToCommit(path: string, commitId: string): Promise<string> {
     return invoke("reset_to_commit", { path, commitId });
   },
 
-  async discardChanges(path: string): Promise<string> {
-    return invoke("discard_changes", { path });
+  async mergeBranch(path: string, branch: string): Promise<MergeResult> {
+    return invoke("merge_branch", { path, branch });
+  },
+
+  async abortMerge(path: string): Promise<string> {
+    return invoke("abort_merge", { path });
+  },
+
+  async listConflicts(path: string): Promise<ConflictEntry[]> {
+    return invoke("list_conflicts", { path });
+  },
+
+  async resolveConflict(path: string, file: string): Promise<string> {
+    return invoke("resolve_conflict", { path, file });
+  },
+
+  async rebaseOnto(path: string, baseBranch: string): Promise<RebaseResult> {
+    return invoke("rebase_onto", { path, baseBranch });
+  },
+
+  async rebaseContinue(path: string): Promise<RebaseResult> {
+    return invoke("rebase_continue", { path });
+  },
+
+  async rebaseAbort(path: string): Promise<string> {
+    return invoke("rebase_abort", { path });
+  },
+
+  async discardFile(path: string, file: string): Promise<string> {
+    return invoke("discard_file", { path, file });
+  },
+
+  async discardChanges(path: string, removeUntracked = false): Promise<string> {
+    return invoke("discard_changes", { path, removeUntracked });
   },
 
   async createBranch(path: string, branch: string): Promise<string> {
@@ -105,13 +286,35 @@ export const gitService = {
     return invoke("checkout", { path, branch });
   },
 
-  async push(path: string, remote: string, branch: string): Promise<string> {
+  async push(
+    path: string,
+    remote: string,
+    branch: string,
+    options: PushOptions = {},
+  ): Promise<string> {
     const key = requireSSHKey();
     return invoke("push", {
       path,
       remote,
       branch,
       sshKeyPath: key.private_key_path,
+      force: options.force ?? "none",
+      remoteBranch: options.remoteBranch ?? null,
+      setUpstream: options.setUpstream ?? false,
+    });
+  },
+
+  async deleteRemoteBranch(
+    path: string,
+    remote: string,
+    branch: string,
+  ): Promise<string> {
+    const key = requireSSHKey();
+    return invoke("delete_remote_branch", {
+      path,
+      remote,
+      branch,
+      sshKeyPath: key.private_key_path,
     });
   },
 
@@ -125,6 +328,23 @@ export const gitService = {
     });
   },
 
+  async listWorktrees(path: string): Promise<WorktreeInfo[]> {
+    return invoke("list_worktrees", { path });
+  },
+
+  async addWorktree(
+    path: string,
+    name: string,
+    branch: string,
+    dest: string,
+  ): Promise<WorktreeInfo> {
+    return invoke("add_worktree", { path, name, branch, dest });
+  },
+
+  async removeWorktree(path: string, name: string): Promise<string> {
+    return invoke("remove_worktree", { path, name });
+  },
+
   async getRemotes(path: string): Promise<string[]> {
     return invoke("get_remotes", { path });
   },
@@ -133,8 +353,37 @@ export const gitService = {
     return invoke("get_remote_url", { path, remote });
   },
 
+  async setRemoteUrl(path: string, remote: string, url: string): Promise<string> {
+    return invoke("set_remote_url", { path, remote, url });
+  },
+
+  async removeRemote(path: string, remote: string): Promise<string> {
+    return invoke("remove_remote", { path, remote });
+  },
+
   async saveRepo(name: string, path: string): Promise<void> {
-    return invoke("save_repo_entry", { name, path });
+    const key = configService.getSelectedSSHKey();
+    return invoke("save_repo_entry", {
+      name,
+      path,
+      sshKeyPath: key?.private_key_path ?? null,
+    });
+  },
+
+  async pruneMissingRepos(): Promise<string[]> {
+    return invoke("prune_missing_repos");
+  },
+
+  async setRepoTags(path: string, tags: string[]): Promise<void> {
+    return invoke("set_repo_tags", { path, tags });
+  },
+
+  async listReposByTag(tag: string): Promise<SavedRepo[]> {
+    return invoke("list_repos_by_tag", { tag });
+  },
+
+  async setRepoSSHKey(path: string, keyPath: string | null): Promise<void> {
+    return invoke("set_repo_ssh_key", { path, keyPath });
   },
 
   async listSavedRepos(): Promise<SavedRepo[]> {
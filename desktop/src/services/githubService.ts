@@ -1,4 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
+import type { CloneProtocol } from "@/services/gitService";
 
 export interface GithubDeviceResponse {
   device_code: string;
@@ -42,6 +43,12 @@ export interface GithubRepo {
   owner: GithubRepoOwner;
 }
 
+export interface GithubBranch {
+  name: string;
+  sha: string;
+  protected: boolean;
+}
+
 export interface GithubPullRequest {
   id: number;
   number: number;
@@ -49,6 +56,20 @@ export interface GithubPullRequest {
   url: string;
   state: string;
   title: string;
+  draft: boolean;
+}
+
+export interface GithubPullRequestDefaults {
+  base: string;
+  head: string;
+}
+
+export type GithubReviewEvent = "APPROVE" | "REQUEST_CHANGES" | "COMMENT";
+
+export interface GithubReview {
+  id: number;
+  state: string;
+  html_url?: string | null;
 }
 
 export interface GithubCollaborator {
@@ -90,6 +111,14 @@ export const githubService = {
     return invoke<void>("github_clear_token");
   },
 
+  async checkTokenScopes(): Promise<string[]> {
+    return invoke<string[]>("github_check_token_scopes");
+  },
+
+  async verifyTokenScopes(): Promise<void> {
+    return invoke<void>("github_verify_token_scopes");
+  },
+
   async getUser(): Promise<GithubUser> {
     return invoke<GithubUser>("github_get_user");
   },
@@ -108,6 +137,30 @@ export const githubService = {
     });
   },
 
+  async listBranches(owner: string, repo: string): Promise<GithubBranch[]> {
+    return invoke<GithubBranch[]>("github_list_branches", { owner, repo });
+  },
+
+  async getRepoCloneUrl(
+    owner: string,
+    repo: string,
+    protocol: CloneProtocol,
+  ): Promise<string> {
+    return invoke<string>("github_repo_clone_url", { owner, repo, protocol });
+  },
+
+  async getPullRequestDefaults(
+    repoPath: string,
+    owner: string,
+    repo: string,
+  ): Promise<GithubPullRequestDefaults> {
+    return invoke<GithubPullRequestDefaults>("github_pr_defaults", {
+      repoPath,
+      owner,
+      repo,
+    });
+  },
+
   async createPullRequest(input: {
     repoFullName: string;
     title: string;
@@ -142,6 +195,42 @@ export const githubService = {
     });
   },
 
+  async forkRepo(
+    owner: string,
+    repo: string,
+    organization?: string | null,
+  ): Promise<GithubRepo> {
+    return invoke<GithubRepo>("github_fork_repo", {
+      owner,
+      repo,
+      organization: organization ?? null,
+    });
+  },
+
+  async markPullRequestReady(
+    owner: string,
+    repo: string,
+    number: number,
+  ): Promise<void> {
+    return invoke<void>("github_mark_pr_ready", { owner, repo, number });
+  },
+
+  async submitReview(
+    owner: string,
+    repo: string,
+    number: number,
+    event: GithubReviewEvent,
+    body?: string | null,
+  ): Promise<GithubReview> {
+    return invoke<GithubReview>("github_submit_review", {
+      owner,
+      repo,
+      number,
+      event,
+      body: body ?? null,
+    });
+  },
+
   async addSshKey(title: string, key: string): Promise<void> {
     return invoke<void>("github_add_ssh_key", { title, key });
   },
@@ -120,6 +120,8 @@ const DEFAULT_RETRY_OPTIONS: RetryOptions = {
   backoffMultiplier: 2,
 };
 
+const CANCELED_MESSAGE = "OpenCode request canceled";
+
 async function withRetry<T>(
   fn: () => Promise<T>,
   options: RetryOptions = DEFAULT_RETRY_OPTIONS,
@@ -131,6 +133,9 @@ async function withRetry<T>(
       return await fn();
     } catch (err) {
       lastError = err as Error;
+      if (String(err).includes(CANCELED_MESSAGE)) {
+        break;
+      }
       if (attempt < options.maxRetries) {
         const delay = options.delayMs * Math.pow(options.backoffMultiplier, attempt);
         await new Promise((resolve) => setTimeout(resolve, delay));
@@ -145,9 +150,16 @@ async function sendCommand(
   cmd: string,
   args: Record<string, unknown>,
   directory?: string,
+  requestId?: string,
 ) {
   const payload = directory ? { ...args, directory } : args;
-  return invoke("opencode_send", { cmd, args: payload });
+  return invoke("opencode_send", {
+    cmd,
+    args: payload,
+    model: null,
+    provider: null,
+    requestId: requestId ?? null,
+  });
 }
 
 export const opencodeService = {
@@ -242,6 +254,7 @@ export const opencodeService = {
     directory?: string,
     system?: string,
     parts?: OpenCodePartInput[],
+    requestId?: string,
   ): Promise<{
     messageId?: string;
     sessionId?: string;
@@ -255,6 +268,7 @@ export const opencodeService = {
         "prompt",
         { sessionPath, message, model, messageID: messageId, system, parts },
         directory,
+        requestId,
       ),
     ) as Promise<{
       messageId?: string;
@@ -266,6 +280,14 @@ export const opencodeService = {
     }>;
   },
 
+  async listModels(provider?: string): Promise<string[]> {
+    return invoke<string[]>("opencode_list_models", { provider: provider ?? null });
+  },
+
+  async cancelRequest(requestId: string): Promise<boolean> {
+    return invoke<boolean>("opencode_cancel", { requestId });
+  },
+
   async sendPromptAsync(
     sessionPath: string,
     message: string,
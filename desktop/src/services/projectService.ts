@@ -31,7 +31,25 @@ export interface CreateAstroProjectResult {
   branch?: string | null;
 }
 
+export type ProjectTemplate = "astro" | "vite" | "next" | "node";
+
+export interface CreateProjectInput {
+  template: ProjectTemplate;
+  name: string;
+  path: string;
+  progressId?: string | null;
+}
+
+export interface CreateProjectResult {
+  path: string;
+  packageManager: string;
+}
+
 export const projectService = {
+  async createProject(input: CreateProjectInput): Promise<CreateProjectResult> {
+    return invoke<CreateProjectResult>("create_project", { input });
+  },
+
   async createAstroProject(
     input: CreateAstroProjectInput,
   ): Promise<CreateAstroProjectResult> {
@@ -8,4 +8,12 @@ export const settingsService = {
   async setDefaultRepoDir(path: string): Promise<void> {
     return invoke("set_default_repo_directory", { path });
   },
+
+  async getGitNetworkTimeout(): Promise<number> {
+    return invoke("get_git_network_timeout");
+  },
+
+  async setGitNetworkTimeout(seconds: number | null): Promise<void> {
+    return invoke("set_git_network_timeout", { seconds });
+  },
 };